@@ -0,0 +1,104 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// A terminal color, written in the config as a name (`"light yellow"`), a hex code
+/// (`"#ffcc00"`) or a 256-color palette index (`"214"`)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Color {
+    Reset,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+    DarkGray,
+    LightRed,
+    LightGreen,
+    LightYellow,
+    LightBlue,
+    LightMagenta,
+    LightCyan,
+    White,
+    Rgb(u8, u8, u8),
+    Indexed(u8),
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Color, String> {
+        let normalized = s
+            .to_lowercase()
+            .replace([' ', '-', '_'], "")
+            .replace("grey", "gray");
+
+        let color = match normalized.as_str() {
+            "reset" => Color::Reset,
+            "black" => Color::Black,
+            "red" => Color::Red,
+            "green" => Color::Green,
+            "yellow" => Color::Yellow,
+            "blue" => Color::Blue,
+            "magenta" => Color::Magenta,
+            "cyan" => Color::Cyan,
+            "gray" => Color::Gray,
+            "darkgray" => Color::DarkGray,
+            "lightred" => Color::LightRed,
+            "lightgreen" => Color::LightGreen,
+            "lightyellow" => Color::LightYellow,
+            "lightblue" => Color::LightBlue,
+            "lightmagenta" => Color::LightMagenta,
+            "lightcyan" => Color::LightCyan,
+            "white" => Color::White,
+            _ => {
+                if let Ok(index) = normalized.parse::<u8>() {
+                    Color::Indexed(index)
+                } else if let Some(hex) = normalized.strip_prefix('#').filter(|hex| hex.len() == 6)
+                {
+                    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16);
+                    match (channel(0), channel(2), channel(4)) {
+                        (Ok(r), Ok(g), Ok(b)) => Color::Rgb(r, g, b),
+                        _ => return Err(format!("`{}` is not a valid hex color", s)),
+                    }
+                } else {
+                    return Err(format!(
+                        "unknown color `{}`, expected a color name, a hex code like \"#ffcc00\" or a palette index",
+                        s
+                    ));
+                }
+            }
+        };
+
+        Ok(color)
+    }
+}
+
+impl TryFrom<String> for Color {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Color, String> {
+        s.parse()
+    }
+}
+
+impl From<Color> for String {
+    fn from(color: Color) -> String {
+        color.to_string()
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Color::Rgb(r, g, b) => write!(f, "#{:02x}{:02x}{:02x}", r, g, b),
+            Color::Indexed(index) => write!(f, "{}", index),
+            named => write!(f, "{:?}", named),
+        }
+    }
+}
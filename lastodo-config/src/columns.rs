@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use crate::Color;
+
+/// One column of the board, written in the config as a `[[columns]]` table
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ColumnConfig {
+    pub name: String,
+    /// The color of the column's title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+    /// Whether tasks in this column are finished
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub done: bool,
+    /// The most tasks the column should hold, its work-in-progress limit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl ColumnConfig {
+    fn new(name: &str, done: bool) -> ColumnConfig {
+        ColumnConfig {
+            name: name.to_string(),
+            color: None,
+            done,
+            limit: None,
+        }
+    }
+}
+
+/// The columns of a board from left to right. There is at least one, their names and their
+/// [`slug`]s are unique, and at most one of them is the done column.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<ColumnConfig>", into = "Vec<ColumnConfig>")]
+pub struct ColumnList(Vec<ColumnConfig>);
+
+impl ColumnList {
+    pub fn iter(&self) -> impl Iterator<Item = &ColumnConfig> {
+        self.0.iter()
+    }
+}
+
+impl Default for ColumnList {
+    fn default() -> ColumnList {
+        ColumnList(vec![
+            ColumnConfig::new("Todo", false),
+            ColumnConfig::new("Partial", false),
+            ColumnConfig::new("Doing", false),
+            ColumnConfig::new("Done", true),
+            ColumnConfig::new("Blocked", false),
+        ])
+    }
+}
+
+impl TryFrom<Vec<ColumnConfig>> for ColumnList {
+    type Error = String;
+
+    fn try_from(mut columns: Vec<ColumnConfig>) -> Result<ColumnList, String> {
+        if columns.is_empty() {
+            return Err(String::from("a board needs at least one column"));
+        }
+
+        for i in 0..columns.len() {
+            columns[i].name = columns[i].name.trim().to_string();
+            let name = &columns[i].name;
+            if name.is_empty() {
+                return Err(String::from("a column needs a name"));
+            }
+            if columns[..i]
+                .iter()
+                .any(|column| column.name.eq_ignore_ascii_case(name))
+            {
+                return Err(format!("there is more than one column named `{}`", name));
+            }
+            // Actions like `move_to_<slug>` are named after the slug, so `Review!` and `Review?`
+            // can't both be columns
+            if let Some(column) = columns[..i]
+                .iter()
+                .find(|column| slug(&column.name) == slug(name))
+            {
+                return Err(format!(
+                    "the columns `{}` and `{}` are too alike, as both become `{}` in action names",
+                    column.name,
+                    name,
+                    slug(name)
+                ));
+            }
+            if columns[i].limit == Some(0) {
+                return Err(format!("the limit of `{}` has to be at least 1", name));
+            }
+        }
+
+        if columns.iter().filter(|column| column.done).count() > 1 {
+            return Err(String::from("only one column can be the done column"));
+        }
+
+        Ok(ColumnList(columns))
+    }
+}
+
+/// `name` in lower case with anything but letters and digits turned into `_`, for places
+/// that can't have spaces
+pub fn slug(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+impl From<ColumnList> for Vec<ColumnConfig> {
+    fn from(columns: ColumnList) -> Vec<ColumnConfig> {
+        columns.0
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// Why a config file could not be loaded
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The file exists but could not be read
+    Io { path: PathBuf, source: io::Error },
+    /// The file was read, but something in it is wrong
+    Invalid {
+        path: PathBuf,
+        location: Option<Location>,
+        message: String,
+    },
+}
+
+/// Where in the config file an error is, with the offending line for context
+#[derive(Debug, Clone)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+    pub source_line: String,
+    pub width: usize,
+}
+
+impl Location {
+    /// Turn a byte range in `source` into a 1-based line and column
+    pub fn from_span(source: &str, span: Range<usize>) -> Location {
+        let start = span.start.min(source.len());
+        let line_start = source[..start].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = source[start..]
+            .find('\n')
+            .map_or(source.len(), |i| start + i);
+        let source_line = source[line_start..line_end]
+            .trim_end_matches('\r')
+            .to_string();
+
+        Location {
+            line: source[..start].matches('\n').count() + 1,
+            column: source[line_start..start].chars().count() + 1,
+            width: source[start..span.end.clamp(start, line_end)]
+                .chars()
+                .count()
+                .max(1),
+            source_line,
+        }
+    }
+}
+
+impl ConfigError {
+    /// An error about the value at `span` in `source`, for checks that can't be done while
+    /// deserializing
+    pub fn at(path: &Path, source: &str, span: Range<usize>, message: String) -> ConfigError {
+        ConfigError::Invalid {
+            path: path.to_path_buf(),
+            location: Some(Location::from_span(source, span)),
+            message,
+        }
+    }
+
+    pub(crate) fn from_toml(path: &Path, source: &str, err: toml::de::Error) -> ConfigError {
+        ConfigError::Invalid {
+            path: path.to_path_buf(),
+            location: err.span().map(|span| Location::from_span(source, span)),
+            message: err.message().to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::Io { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            ConfigError::Invalid {
+                path,
+                location: None,
+                message,
+            } => write!(f, "{}: {}", path.display(), message),
+            ConfigError::Invalid {
+                path,
+                location: Some(location),
+                message,
+            } => {
+                let gutter = " ".repeat(location.line.to_string().len());
+                writeln!(
+                    f,
+                    "{}:{}:{}: {}",
+                    path.display(),
+                    location.line,
+                    location.column,
+                    message
+                )?;
+                writeln!(f, "{} |", gutter)?;
+                writeln!(f, "{} | {}", location.line, location.source_line)?;
+                write!(
+                    f,
+                    "{} | {}{}",
+                    gutter,
+                    " ".repeat(location.column - 1),
+                    "^".repeat(location.width)
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io { source, .. } => Some(source),
+            ConfigError::Invalid { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use indexmap::IndexMap;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::Deserialize;
+use toml::Spanned;
+
+/// The `[keys]` section: a preset to start from, plus per-screen overrides that map an action
+/// name to the keys that trigger it.
+///
+/// ```toml
+/// [keys]
+/// preset = "vim"
+///
+/// [keys.main]
+/// cursor_up = "j"
+/// cursor_down = ["k", "<Down>"]
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeysConfig {
+    pub preset: Preset,
+    /// Bindings for the board
+    pub main: IndexMap<Spanned<String>, KeyBindings>,
+    /// Bindings for the task editor, outside of insert mode
+    pub editing: IndexMap<Spanned<String>, KeyBindings>,
+    /// Bindings for the task editor in insert mode. Unbound printable keys are typed.
+    pub insert: IndexMap<Spanned<String>, KeyBindings>,
+    /// Bindings for the command palette. Unbound printable keys are typed.
+    pub palette: IndexMap<Spanned<String>, KeyBindings>,
+    /// Bindings for yes/no confirmation popups
+    pub confirm: IndexMap<Spanned<String>, KeyBindings>,
+    /// Bindings for the archive of deleted tasks
+    pub archive: IndexMap<Spanned<String>, KeyBindings>,
+    /// Bindings for the board picker
+    pub boards: IndexMap<Spanned<String>, KeyBindings>,
+    /// Bindings for typing a search. Unbound printable keys are typed.
+    pub search: IndexMap<Spanned<String>, KeyBindings>,
+    /// Bindings for typing a filter. Unbound printable keys are typed.
+    pub filter: IndexMap<Spanned<String>, KeyBindings>,
+}
+
+/// A built-in set of bindings
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Preset {
+    #[default]
+    Vim,
+    Arrows,
+}
+
+/// One key press along with the modifiers held down, e.g. `<C-r>`.
+///
+/// Shift is folded into the character for printable keys, so `<S-h>` and `H` are the same chord.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyChord {
+    pub code: KeyCode,
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> KeyChord {
+        match code {
+            KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => KeyChord {
+                code: KeyCode::Char(c.to_ascii_uppercase()),
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => KeyChord {
+                code: KeyCode::BackTab,
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            KeyCode::BackTab => KeyChord {
+                code: KeyCode::BackTab,
+                modifiers: modifiers - KeyModifiers::SHIFT,
+            },
+            _ => KeyChord { code, modifiers },
+        }
+    }
+
+    /// Parse a single chord written as either one character or a `<...>` name
+    fn parse(s: &str) -> Result<KeyChord, String> {
+        let Some(inner) = s.strip_prefix('<').and_then(|s| s.strip_suffix('>')) else {
+            let mut chars = s.chars();
+            return match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(KeyChord::new(KeyCode::Char(c), KeyModifiers::NONE)),
+                _ => Err(format!("`{}` is not a single key", s)),
+            };
+        };
+
+        // Everything before the last `-` is a modifier, e.g. `C-S-Tab`. A trailing `-` is the
+        // minus key itself, e.g. `C--`.
+        let (modifier_part, key_part) = match inner.strip_suffix("--") {
+            Some(modifiers) => (Some(modifiers), "-"),
+            None => match inner.rsplit_once('-') {
+                Some((modifiers, key)) => (Some(modifiers), key),
+                None => (None, inner),
+            },
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier in modifier_part.into_iter().flat_map(|part| part.split('-')) {
+            modifiers |= match modifier.to_ascii_uppercase().as_str() {
+                "C" => KeyModifiers::CONTROL,
+                "S" => KeyModifiers::SHIFT,
+                "A" | "M" => KeyModifiers::ALT,
+                _ => return Err(format!("unknown modifier `{}` in `{}`", modifier, s)),
+            };
+        }
+
+        let code = match key_part.to_ascii_lowercase().as_str() {
+            "esc" => KeyCode::Esc,
+            "cr" | "enter" | "return" => KeyCode::Enter,
+            "tab" => KeyCode::Tab,
+            "bs" | "backspace" => KeyCode::Backspace,
+            "del" | "delete" => KeyCode::Delete,
+            "space" => KeyCode::Char(' '),
+            "lt" => KeyCode::Char('<'),
+            "up" => KeyCode::Up,
+            "down" => KeyCode::Down,
+            "left" => KeyCode::Left,
+            "right" => KeyCode::Right,
+            "home" => KeyCode::Home,
+            "end" => KeyCode::End,
+            "pageup" => KeyCode::PageUp,
+            "pagedown" => KeyCode::PageDown,
+            name => match name.strip_prefix('f').map(str::parse::<u8>) {
+                Some(Ok(n)) if (1..=12).contains(&n) => KeyCode::F(n),
+                _ => {
+                    let mut chars = key_part.chars();
+                    match (chars.next(), chars.next()) {
+                        // Ctrl and Alt chords are reported in lowercase unless shift is held
+                        (Some(c), None) => KeyCode::Char(c.to_ascii_lowercase()),
+                        _ => return Err(format!("unknown key `{}` in `{}`", key_part, s)),
+                    }
+                }
+            },
+        };
+
+        Ok(KeyChord::new(code, modifiers))
+    }
+}
+
+impl From<KeyEvent> for KeyChord {
+    fn from(event: KeyEvent) -> KeyChord {
+        KeyChord::new(event.code, event.modifiers)
+    }
+}
+
+impl fmt::Display for KeyChord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self.code {
+            KeyCode::Char(' ') => "Space".to_string(),
+            KeyCode::Char('<') => "lt".to_string(),
+            KeyCode::Char(c) => c.to_string(),
+            KeyCode::Esc => "Esc".to_string(),
+            KeyCode::Enter => "Enter".to_string(),
+            KeyCode::Tab => "Tab".to_string(),
+            KeyCode::BackTab => "S-Tab".to_string(),
+            KeyCode::Backspace => "BS".to_string(),
+            KeyCode::Delete => "Del".to_string(),
+            KeyCode::F(n) => format!("F{}", n),
+            other => format!("{:?}", other),
+        };
+
+        let mut prefix = String::new();
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            prefix.push_str("C-");
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            prefix.push_str("A-");
+        }
+
+        match self.code {
+            KeyCode::Char(c) if prefix.is_empty() && c != ' ' && c != '<' => write!(f, "{}", name),
+            _ => write!(f, "<{}{}>", prefix, name),
+        }
+    }
+}
+
+/// A sequence of chords that triggers an action, e.g. `gg` or `<C-w>j`
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize)]
+#[serde(try_from = "String")]
+pub struct KeySequence(pub Vec<KeyChord>);
+
+impl std::str::FromStr for KeySequence {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<KeySequence, String> {
+        let mut chords = Vec::new();
+        let mut rest = s;
+
+        while let Some(c) = rest.chars().next() {
+            let len = match (c, rest.find('>')) {
+                ('<', Some(end)) if end > 1 => end + 1,
+                _ => c.len_utf8(),
+            };
+            chords.push(KeyChord::parse(&rest[..len])?);
+            rest = &rest[len..];
+        }
+
+        if chords.is_empty() {
+            return Err("a key binding can't be empty".to_string());
+        }
+
+        Ok(KeySequence(chords))
+    }
+}
+
+impl TryFrom<String> for KeySequence {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<KeySequence, String> {
+        s.parse()
+    }
+}
+
+impl fmt::Display for KeySequence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for chord in &self.0 {
+            write!(f, "{}", chord)?;
+        }
+        Ok(())
+    }
+}
+
+/// The keys bound to one action, written as a single string or a list. An empty list unbinds
+/// the action.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyBindings(pub Vec<KeySequence>);
+
+impl<'de> Deserialize<'de> for KeyBindings {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<KeyBindings, D::Error> {
+        struct BindingsVisitor;
+
+        impl<'de> Visitor<'de> for BindingsVisitor {
+            type Value = KeyBindings;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a key like \"gg\" or a list of keys")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<KeyBindings, E> {
+                Ok(KeyBindings(vec![s.parse().map_err(E::custom)?]))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<KeyBindings, A::Error> {
+                let mut sequences = Vec::new();
+                while let Some(sequence) = seq.next_element()? {
+                    sequences.push(sequence);
+                }
+                Ok(KeyBindings(sequences))
+            }
+        }
+
+        deserializer.deserialize_any(BindingsVisitor)
+    }
+}
@@ -0,0 +1,162 @@
+//! Loading and validation of the lastodo config file, `~/.config/lastodo/config.toml`.
+//!
+//! Every setting has a default, so a missing file or a missing section is fine. Anything that
+//! is present is validated while it is parsed, and errors point at the offending line.
+
+mod color;
+mod columns;
+mod error;
+mod keys;
+
+use std::env;
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use chrono::format::{Item, StrftimeItems};
+use indexmap::IndexMap;
+use serde::Deserialize;
+use toml::Spanned;
+
+pub use color::Color;
+pub use columns::{slug, ColumnConfig, ColumnList};
+pub use error::{ConfigError, Location};
+pub use keys::{KeyBindings, KeyChord, KeySequence, KeysConfig, Preset};
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub storage: StorageConfig,
+    pub ui: UiConfig,
+    pub keys: KeysConfig,
+    pub history: HistoryConfig,
+    /// The columns of boards that don't define their own
+    pub columns: ColumnList,
+    /// Filters to pick by name, each a query like `due:<7d status:!done`
+    pub filters: IndexMap<String, Spanned<String>>,
+
+    /// The file this config was read from and its contents, for pointing errors at it
+    #[serde(skip)]
+    origin: Option<(PathBuf, String)>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StorageConfig {
+    /// Where boards are kept. Defaults to the XDG data directory.
+    pub data_dir: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UiConfig {
+    /// Background of the highlighted task and the focused field
+    pub highlight_bg: Color,
+    /// Foreground of the highlighted task and the focused field
+    pub highlight_fg: Color,
+    /// How due dates are shown, as a `strftime` format
+    pub date_format: DateFormat,
+}
+
+impl Default for UiConfig {
+    fn default() -> UiConfig {
+        UiConfig {
+            highlight_bg: Color::LightYellow,
+            highlight_fg: Color::Black,
+            date_format: DateFormat("%Y-%m-%d".to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HistoryConfig {
+    /// How many changes to the board can be undone
+    pub depth: usize,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> HistoryConfig {
+        HistoryConfig { depth: 100 }
+    }
+}
+
+/// A `strftime` format string that is known to be valid
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct DateFormat(String);
+
+impl DateFormat {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for DateFormat {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<DateFormat, String> {
+        if StrftimeItems::new(&s).any(|item| item == Item::Error) {
+            return Err(format!("`{}` is not a valid strftime format", s));
+        }
+
+        Ok(DateFormat(s))
+    }
+}
+
+impl Config {
+    /// `$XDG_CONFIG_HOME/lastodo/config.toml`, falling back to `~/.config/lastodo/config.toml`
+    pub fn default_path() -> Option<PathBuf> {
+        let config_home = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute())
+            .or_else(|| dirs::home_dir().map(|home| home.join(".config")))?;
+
+        Some(config_home.join("lastodo").join("config.toml"))
+    }
+
+    /// Load the config at `path`. A file that doesn't exist gives the default config.
+    pub fn load(path: &Path) -> Result<Config, ConfigError> {
+        match fs::read_to_string(path) {
+            Ok(source) => Config::parse(path, &source),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(source) => Err(ConfigError::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    /// Parse and validate `source`, which was read from `path`
+    pub fn parse(path: &Path, source: &str) -> Result<Config, ConfigError> {
+        let mut config: Config =
+            toml::from_str(source).map_err(|err| ConfigError::from_toml(path, source, err))?;
+
+        config.storage.data_dir = config.storage.data_dir.map(expand_tilde);
+        config.origin = Some((path.to_path_buf(), source.to_string()));
+
+        Ok(config)
+    }
+
+    /// An error about the value at `span`, for checks that need more than the config file to
+    /// do, like whether an action name exists
+    pub fn error_at(&self, span: Range<usize>, message: String) -> ConfigError {
+        match &self.origin {
+            Some((path, source)) => ConfigError::at(path, source, span, message),
+            None => ConfigError::Invalid {
+                path: PathBuf::new(),
+                location: None,
+                message,
+            },
+        }
+    }
+}
+
+/// Replace a leading `~` with the home directory
+fn expand_tilde(path: PathBuf) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path,
+    }
+}
@@ -0,0 +1,51 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen, Task, TaskEditMode, TaskEditState};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn add_task(app: &mut App) -> Result<(), String> {
+    let status = app
+        .cur_task
+        .as_ref()
+        .ok_or("There is no column to add a task to.")?
+        .status
+        .clone();
+
+    // The draft stays out of the task list until it's saved, so discarding it leaves the
+    // board untouched
+    app.currently_editing_task = Some(TaskEditState::new(
+        Task {
+            kanban_status: status,
+            ..Task::default()
+        },
+        true,
+        TaskEditMode::Insert,
+    ));
+    app.current_screen = CurrentScreen::Editing;
+
+    Ok(())
+}
+
+pub struct AddTask;
+
+impl Action for AddTask {
+    fn name(&self) -> &str {
+        "add_task"
+    }
+
+    fn description(&self) -> &str {
+        "Add a task to the focused column"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["a"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        add_task(app)
+    }
+}
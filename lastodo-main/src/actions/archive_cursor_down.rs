@@ -0,0 +1,36 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn archive_cursor_down(app: &mut App) -> Result<(), String> {
+    if app.archive_index + 1 < app.archive.len() {
+        app.archive_index += 1;
+    }
+
+    Ok(())
+}
+
+pub struct ArchiveCursorDown;
+
+impl Action for ArchiveCursorDown {
+    fn name(&self) -> &str {
+        "archive_cursor_down"
+    }
+
+    fn description(&self) -> &str {
+        "Highlight the next archived task"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Archive
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["j"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        archive_cursor_down(app)
+    }
+}
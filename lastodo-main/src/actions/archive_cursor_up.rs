@@ -0,0 +1,34 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn archive_cursor_up(app: &mut App) -> Result<(), String> {
+    app.archive_index = app.archive_index.saturating_sub(1);
+
+    Ok(())
+}
+
+pub struct ArchiveCursorUp;
+
+impl Action for ArchiveCursorUp {
+    fn name(&self) -> &str {
+        "archive_cursor_up"
+    }
+
+    fn description(&self) -> &str {
+        "Highlight the previous archived task"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Archive
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["k"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        archive_cursor_up(app)
+    }
+}
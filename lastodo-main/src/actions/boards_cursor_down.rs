@@ -0,0 +1,36 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn boards_cursor_down(app: &mut App) -> Result<(), String> {
+    if app.board_index + 1 < app.boards.len() {
+        app.board_index += 1;
+    }
+
+    Ok(())
+}
+
+pub struct BoardsCursorDown;
+
+impl Action for BoardsCursorDown {
+    fn name(&self) -> &str {
+        "boards_cursor_down"
+    }
+
+    fn description(&self) -> &str {
+        "Highlight the next board"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Boards
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["j"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        boards_cursor_down(app)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn boards_cursor_up(app: &mut App) -> Result<(), String> {
+    app.board_index = app.board_index.saturating_sub(1);
+
+    Ok(())
+}
+
+pub struct BoardsCursorUp;
+
+impl Action for BoardsCursorUp {
+    fn name(&self) -> &str {
+        "boards_cursor_up"
+    }
+
+    fn description(&self) -> &str {
+        "Highlight the previous board"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Boards
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["k"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        boards_cursor_up(app)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn cancel_confirm(app: &mut App) -> Result<(), String> {
+    app.current_screen = app
+        .confirmation
+        .take()
+        .map_or(CurrentScreen::Main, |confirmation| confirmation.screen());
+
+    Ok(())
+}
+
+pub struct CancelConfirm;
+
+impl Action for CancelConfirm {
+    fn name(&self) -> &str {
+        "cancel_confirm"
+    }
+
+    fn description(&self) -> &str {
+        "Back out of what is being confirmed"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Confirm
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["n", "<Esc>", "q"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        cancel_confirm(app)
+    }
+}
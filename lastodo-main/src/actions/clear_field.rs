@@ -0,0 +1,40 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn clear_field(app: &mut App) -> Result<(), String> {
+    let state = app
+        .currently_editing_task
+        .as_mut()
+        .ok_or("No task is currently being edited.")?;
+
+    state.cur_value.clear();
+    state.apply_value();
+
+    Ok(())
+}
+
+pub struct ClearField;
+
+impl Action for ClearField {
+    fn name(&self) -> &str {
+        "clear_field"
+    }
+
+    fn description(&self) -> &str {
+        "Erase everything in the focused field"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Insert
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<C-u>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        clear_field(app)
+    }
+}
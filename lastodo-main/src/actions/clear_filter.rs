@@ -0,0 +1,32 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn clear_filter(app: &mut App) -> Result<(), String> {
+    app.apply_filter("")
+}
+
+pub struct ClearFilter;
+
+impl Action for ClearFilter {
+    fn name(&self) -> &str {
+        "clear_filter"
+    }
+
+    fn description(&self) -> &str {
+        "Show every task again"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["F"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        clear_filter(app)
+    }
+}
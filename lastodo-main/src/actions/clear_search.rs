@@ -0,0 +1,34 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn clear_search(app: &mut App) -> Result<(), String> {
+    app.search = None;
+
+    Ok(())
+}
+
+pub struct ClearSearch;
+
+impl Action for ClearSearch {
+    fn name(&self) -> &str {
+        "clear_search"
+    }
+
+    fn description(&self) -> &str {
+        "Stop highlighting the tasks that match the search"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<Esc>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        clear_search(app)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn close_archive(app: &mut App) -> Result<(), String> {
+    app.current_screen = CurrentScreen::Main;
+
+    Ok(())
+}
+
+pub struct CloseArchive;
+
+impl Action for CloseArchive {
+    fn name(&self) -> &str {
+        "close_archive"
+    }
+
+    fn description(&self) -> &str {
+        "Go back to the board"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Archive
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["q", "<Esc>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        close_archive(app)
+    }
+}
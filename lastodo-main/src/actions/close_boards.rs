@@ -0,0 +1,34 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn close_boards(app: &mut App) -> Result<(), String> {
+    app.current_screen = CurrentScreen::Main;
+
+    Ok(())
+}
+
+pub struct CloseBoards;
+
+impl Action for CloseBoards {
+    fn name(&self) -> &str {
+        "close_boards"
+    }
+
+    fn description(&self) -> &str {
+        "Go back to the board without switching"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Boards
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["q", "<Esc>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        close_boards(app)
+    }
+}
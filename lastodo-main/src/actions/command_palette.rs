@@ -0,0 +1,35 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use crate::palette::PaletteState;
+use std::result::Result;
+
+pub fn command_palette(app: &mut App) -> Result<(), String> {
+    app.palette = Some(PaletteState::default());
+    app.current_screen = CurrentScreen::Palette;
+    Ok(())
+}
+
+pub struct CommandPalette;
+
+impl Action for CommandPalette {
+    fn name(&self) -> &str {
+        "command_palette"
+    }
+
+    fn description(&self) -> &str {
+        "Open the command palette"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &[":"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        command_palette(app)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::actions::move_task_to::move_task;
+use crate::actions::restore_task::restore;
+use crate::actions::save_task::write_task;
+use crate::actions::Action;
+use crate::app::{App, ArchivedTask, Confirmation};
+use crate::history::Change;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn confirm(app: &mut App) -> Result<(), String> {
+    let confirmation = app
+        .confirmation
+        .take()
+        .ok_or("There is nothing to confirm.")?;
+    app.current_screen = confirmation.screen();
+
+    match confirmation {
+        Confirmation::ArchiveTask(id) => {
+            let (status, index) = app
+                .locate(id)
+                .ok_or("The task is no longer on the board.")?;
+            let task = app.task_list[&status].remove(index);
+            app.message = format!("Moved \"{}\" to the archive.", task.title);
+            let archived = ArchivedTask {
+                task,
+                archived_at: chrono::Local::now(),
+            };
+            app.archive.push(archived.clone());
+            app.history.record(Change::Delete { archived, index });
+            app.clamp_cursor();
+        }
+        Confirmation::MoveOverLimit { id, status } => move_task(app, id, status)?,
+        Confirmation::AddOverLimit { .. } => write_task(app)?,
+        Confirmation::RestoreOverLimit { id, .. } => {
+            let archive_index = app
+                .archive
+                .iter()
+                .position(|archived| archived.task.id == id)
+                .ok_or("The task is no longer in the archive.")?;
+            restore(app, archive_index);
+        }
+    }
+
+    Ok(())
+}
+
+pub struct Confirm;
+
+impl Action for Confirm {
+    fn name(&self) -> &str {
+        "confirm"
+    }
+
+    fn description(&self) -> &str {
+        "Go ahead with what is being confirmed"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Confirm
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["y", "<Enter>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        confirm(app)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentTask};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn cursor_down(app: &mut App) -> Result<(), String> {
+    if let Some(cur_task) = &app.cur_task {
+        let visible = app.visible_tasks(&cur_task.status);
+
+        // Wrap around to the top of the column
+        let row = visible
+            .iter()
+            .position(|&index| index as i16 == cur_task.index);
+        let Some(&index) = row.and_then(|row| visible.get(row + 1)).or(visible.first()) else {
+            return Ok(());
+        };
+
+        app.cur_task = Some(CurrentTask {
+            status: cur_task.status.clone(),
+            index: index as i16,
+        });
+    }
+
+    Ok(())
+}
+
+pub struct CursorDown;
+
+impl Action for CursorDown {
+    fn name(&self) -> &str {
+        "cursor_down"
+    }
+
+    fn description(&self) -> &str {
+        "Move the cursor down"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["j"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        cursor_down(app)
+    }
+}
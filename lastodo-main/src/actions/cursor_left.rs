@@ -0,0 +1,37 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn cursor_left(app: &mut App) -> Result<(), String> {
+    if let Some(cur_task) = &app.cur_task {
+        let new_status = app.columns.prev(&cur_task.status);
+        app.focus_column(new_status);
+    }
+
+    Ok(())
+}
+
+pub struct CursorLeft;
+
+impl Action for CursorLeft {
+    fn name(&self) -> &str {
+        "cursor_left"
+    }
+
+    fn description(&self) -> &str {
+        "Move the cursor to the previous column"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["h"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        cursor_left(app)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn cursor_right(app: &mut App) -> Result<(), String> {
+    if let Some(cur_task) = &app.cur_task {
+        let new_status = app.columns.next(&cur_task.status);
+        app.focus_column(new_status);
+    }
+
+    Ok(())
+}
+
+pub struct CursorRight;
+
+impl Action for CursorRight {
+    fn name(&self) -> &str {
+        "cursor_right"
+    }
+
+    fn description(&self) -> &str {
+        "Move the cursor to the next column"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["l"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        cursor_right(app)
+    }
+}
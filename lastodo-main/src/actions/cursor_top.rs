@@ -0,0 +1,40 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn cursor_top(app: &mut App) -> Result<(), String> {
+    let index = app
+        .cur_task
+        .as_ref()
+        .and_then(|cur_task| app.visible_tasks(&cur_task.status).first().copied());
+    if let (Some(cur_task), Some(index)) = (app.cur_task.as_mut(), index) {
+        cur_task.index = index as i16;
+    }
+
+    Ok(())
+}
+
+pub struct CursorTop;
+
+impl Action for CursorTop {
+    fn name(&self) -> &str {
+        "cursor_top"
+    }
+
+    fn description(&self) -> &str {
+        "Jump to the first task in the column"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["gg"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        cursor_top(app)
+    }
+}
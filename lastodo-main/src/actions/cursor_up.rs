@@ -0,0 +1,53 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentTask};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn cursor_up(app: &mut App) -> Result<(), String> {
+    if let Some(cur_task) = &app.cur_task {
+        let visible = app.visible_tasks(&cur_task.status);
+
+        // Wrap around to the bottom of the column
+        let row = visible
+            .iter()
+            .position(|&index| index as i16 == cur_task.index);
+        let Some(&index) = row
+            .and_then(|row| row.checked_sub(1))
+            .and_then(|row| visible.get(row))
+            .or(visible.last())
+        else {
+            return Ok(());
+        };
+
+        app.cur_task = Some(CurrentTask {
+            status: cur_task.status.clone(),
+            index: index as i16,
+        });
+    }
+
+    Ok(())
+}
+
+pub struct CursorUp;
+
+impl Action for CursorUp {
+    fn name(&self) -> &str {
+        "cursor_up"
+    }
+
+    fn description(&self) -> &str {
+        "Move the cursor up"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["k"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        cursor_up(app)
+    }
+}
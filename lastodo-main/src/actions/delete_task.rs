@@ -0,0 +1,37 @@
+use crate::actions::Action;
+use crate::app::{App, Confirmation, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn delete_task(app: &mut App) -> Result<(), String> {
+    let cur_task = app.get_cur_task().ok_or("No task was selected.")?;
+
+    app.confirmation = Some(Confirmation::ArchiveTask(cur_task.id));
+    app.current_screen = CurrentScreen::Confirm;
+
+    Ok(())
+}
+
+pub struct DeleteTask;
+
+impl Action for DeleteTask {
+    fn name(&self) -> &str {
+        "delete_task"
+    }
+
+    fn description(&self) -> &str {
+        "Move the highlighted task to the archive, after confirming"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["dd"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        delete_task(app)
+    }
+}
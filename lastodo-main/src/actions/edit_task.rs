@@ -0,0 +1,41 @@
+use crate::actions::add_task::add_task;
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen, TaskEditMode, TaskEditState};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn edit_task(app: &mut App) -> Result<(), String> {
+    // With nothing to edit, start a new task instead
+    let Some(cur_task) = app.get_cur_task() else {
+        return add_task(app);
+    };
+
+    app.current_screen = CurrentScreen::Editing;
+    app.currently_editing_task = Some(TaskEditState::new(cur_task, false, TaskEditMode::Normal));
+
+    Ok(())
+}
+
+pub struct EditTask;
+
+impl Action for EditTask {
+    fn name(&self) -> &str {
+        "edit_task"
+    }
+
+    fn description(&self) -> &str {
+        "Edit the highlighted task"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["i", "e"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        edit_task(app)
+    }
+}
@@ -0,0 +1,39 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn filter(app: &mut App) -> Result<(), String> {
+    // Start from the filter that's shown, so it can be refined
+    app.filter_input = app
+        .filter
+        .as_ref()
+        .map_or_else(String::new, |filter| filter.query().to_string());
+    app.current_screen = CurrentScreen::Filter;
+
+    Ok(())
+}
+
+pub struct Filter;
+
+impl Action for Filter {
+    fn name(&self) -> &str {
+        "filter"
+    }
+
+    fn description(&self) -> &str {
+        "Only show the tasks matching a query, like `due:<7d status:!done`"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["f"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        filter(app)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn filter_accept(app: &mut App) -> Result<(), String> {
+    // A query that doesn't parse keeps the prompt open, so it can be fixed
+    let query = app.filter_input.clone();
+    app.apply_filter(&query)?;
+    app.current_screen = CurrentScreen::Main;
+
+    Ok(())
+}
+
+pub struct FilterAccept;
+
+impl Action for FilterAccept {
+    fn name(&self) -> &str {
+        "filter_accept"
+    }
+
+    fn description(&self) -> &str {
+        "Show only the tasks matching the typed filter"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Filter
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<Enter>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        filter_accept(app)
+    }
+}
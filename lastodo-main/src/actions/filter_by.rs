@@ -0,0 +1,44 @@
+use crate::actions::Action;
+use crate::app::{self, App};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+/// Shows only the tasks matching one of the filters from the config. There is one of these
+/// per filter.
+pub struct FilterBy {
+    query: String,
+    name: String,
+    description: String,
+}
+
+impl FilterBy {
+    pub fn new(name: &str, query: &str) -> FilterBy {
+        FilterBy {
+            query: query.to_string(),
+            name: format!("filter_by_{}", app::slug(name)),
+            description: format!("Only show the tasks matching `{}`", query),
+        }
+    }
+}
+
+impl Action for FilterBy {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &[]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        app.apply_filter(&self.query)
+    }
+}
@@ -0,0 +1,35 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn filter_cancel(app: &mut App) -> Result<(), String> {
+    app.filter_input.clear();
+    app.current_screen = CurrentScreen::Main;
+
+    Ok(())
+}
+
+pub struct FilterCancel;
+
+impl Action for FilterCancel {
+    fn name(&self) -> &str {
+        "filter_cancel"
+    }
+
+    fn description(&self) -> &str {
+        "Stop typing the filter and keep the one that was shown"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Filter
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<Esc>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        filter_cancel(app)
+    }
+}
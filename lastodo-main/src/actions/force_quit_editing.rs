@@ -1,7 +1,42 @@
+use crate::actions::Action;
 use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
 use std::result::Result;
 
 pub fn force_quit_editing(app: &mut App) -> Result<(), String> {
+    // The board only ever sees saved drafts, so dropping the draft restores the original
+    if let Some(state) = app.currently_editing_task.take() {
+        if state.is_new_task {
+            app.message = String::from("Discarded the new task.");
+        } else if state.has_changed {
+            app.message = String::from("Discarded unsaved changes.");
+        }
+    }
+
     app.current_screen = CurrentScreen::Main;
     Ok(())
 }
+
+pub struct ForceQuitEditing;
+
+impl Action for ForceQuitEditing {
+    fn name(&self) -> &str {
+        "force_quit_editing"
+    }
+
+    fn description(&self) -> &str {
+        "Stop editing and throw away unsaved changes"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Editing
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["x"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        force_quit_editing(app)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::actions::Action;
+use crate::app::{App, TaskEditMode};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn insert_mode(app: &mut App) -> Result<(), String> {
+    let state = app
+        .currently_editing_task
+        .as_mut()
+        .ok_or("No task is currently being edited.")?;
+    state.mode = TaskEditMode::Insert;
+
+    Ok(())
+}
+
+pub struct InsertMode;
+
+impl Action for InsertMode {
+    fn name(&self) -> &str {
+        "insert_mode"
+    }
+
+    fn description(&self) -> &str {
+        "Start typing into the focused field"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Editing
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["i"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        insert_mode(app)
+    }
+}
@@ -1,5 +1,187 @@
+pub mod add_task;
+pub mod archive_cursor_down;
+pub mod archive_cursor_up;
+pub mod boards_cursor_down;
+pub mod boards_cursor_up;
+pub mod cancel_confirm;
+pub mod clear_field;
+pub mod clear_filter;
+pub mod clear_search;
+pub mod close_archive;
+pub mod close_boards;
+pub mod command_palette;
+pub mod confirm;
+pub mod cursor_bottom;
+pub mod cursor_down;
+pub mod cursor_left;
+pub mod cursor_right;
+pub mod cursor_top;
+pub mod cursor_up;
+pub mod delete_task;
+pub mod edit_task;
+pub mod filter;
+pub mod filter_accept;
+pub mod filter_by;
+pub mod filter_cancel;
 pub mod force_quit_editing;
+pub mod insert_mode;
+pub mod move_task_bottom;
+pub mod move_task_down;
+pub mod move_task_left;
+pub mod move_task_right;
+pub mod move_task_to;
+pub mod move_task_top;
+pub mod move_task_up;
 pub mod next_field;
+pub mod normal_mode;
+pub mod open_archive;
+pub mod open_boards;
+pub mod palette_close;
+pub mod palette_next;
+pub mod palette_prev;
+pub mod palette_run;
 pub mod prev_field;
+pub mod quit;
 pub mod quit_editing;
+pub mod redo;
+pub mod reorder;
+pub mod restore_task;
+pub mod save_board;
 pub mod save_task;
+pub mod search;
+pub mod search_accept;
+pub mod search_cancel;
+pub mod search_next;
+pub mod search_prev;
+pub mod sort_column;
+pub mod switch_board;
+pub mod undo;
+
+use std::fmt;
+
+use lastodo_config::Config;
+
+use crate::app::App;
+use crate::columns::Columns;
+use crate::keymap::KeyContext;
+
+/// Something the user can do, by pressing its keys or picking it from the command palette
+pub trait Action {
+    /// The name the action is bound by in the config file
+    fn name(&self) -> &str;
+
+    /// A short explanation, shown in the command palette
+    fn description(&self) -> &str;
+
+    /// The screen the action's keys work on
+    fn context(&self) -> KeyContext;
+
+    /// The keys the action is bound to unless the config says otherwise
+    fn default_binding(&self) -> &[&str];
+
+    fn run(&self, app: &mut App) -> Result<(), String>;
+}
+
+/// Every action, so they can be found by name
+pub struct Registry {
+    actions: Vec<Box<dyn Action>>,
+}
+
+impl Registry {
+    /// All the actions, including moving tasks straight to each of `columns` and showing
+    /// each of the filters in `config`
+    pub fn new(columns: &Columns, config: &Config) -> Registry {
+        let mut actions: Vec<Box<dyn Action>> = vec![
+            Box::new(add_task::AddTask),
+            Box::new(archive_cursor_down::ArchiveCursorDown),
+            Box::new(archive_cursor_up::ArchiveCursorUp),
+            Box::new(boards_cursor_down::BoardsCursorDown),
+            Box::new(boards_cursor_up::BoardsCursorUp),
+            Box::new(cancel_confirm::CancelConfirm),
+            Box::new(clear_field::ClearField),
+            Box::new(clear_filter::ClearFilter),
+            Box::new(clear_search::ClearSearch),
+            Box::new(close_archive::CloseArchive),
+            Box::new(close_boards::CloseBoards),
+            Box::new(command_palette::CommandPalette),
+            Box::new(confirm::Confirm),
+            Box::new(cursor_bottom::CursorBottom),
+            Box::new(cursor_down::CursorDown),
+            Box::new(cursor_left::CursorLeft),
+            Box::new(cursor_right::CursorRight),
+            Box::new(cursor_top::CursorTop),
+            Box::new(cursor_up::CursorUp),
+            Box::new(delete_task::DeleteTask),
+            Box::new(edit_task::EditTask),
+            Box::new(filter::Filter),
+            Box::new(filter_accept::FilterAccept),
+            Box::new(filter_cancel::FilterCancel),
+            Box::new(force_quit_editing::ForceQuitEditing),
+            Box::new(insert_mode::InsertMode),
+            Box::new(move_task_bottom::MoveTaskBottom),
+            Box::new(move_task_down::MoveTaskDown),
+            Box::new(move_task_left::MoveTaskLeft),
+            Box::new(move_task_right::MoveTaskRight),
+            Box::new(move_task_top::MoveTaskTop),
+            Box::new(move_task_up::MoveTaskUp),
+            Box::new(next_field::NextField),
+            Box::new(normal_mode::NormalMode),
+            Box::new(open_archive::OpenArchive),
+            Box::new(open_boards::OpenBoards),
+            Box::new(palette_close::PaletteClose),
+            Box::new(palette_next::PaletteNext),
+            Box::new(palette_prev::PalettePrev),
+            Box::new(palette_run::PaletteRun),
+            Box::new(prev_field::PrevField),
+            Box::new(quit::Quit),
+            Box::new(quit_editing::QuitEditing),
+            Box::new(redo::Redo),
+            Box::new(restore_task::RestoreTask),
+            Box::new(save_board::SaveBoard),
+            Box::new(save_task::SaveTask),
+            Box::new(search::Search),
+            Box::new(search_accept::SearchAccept),
+            Box::new(search_cancel::SearchCancel),
+            Box::new(search_next::SearchNext),
+            Box::new(search_prev::SearchPrev),
+            Box::new(sort_column::SortColumn),
+            Box::new(switch_board::SwitchBoard),
+            Box::new(undo::Undo),
+        ];
+
+        for (position, status) in columns.statuses().enumerate() {
+            actions.push(Box::new(move_task_to::MoveTaskTo::new(
+                status.clone(),
+                position,
+            )));
+        }
+
+        for (name, query) in &config.filters {
+            actions.push(Box::new(filter_by::FilterBy::new(name, query.get_ref())));
+        }
+
+        Registry { actions }
+    }
+
+    pub fn get(&self, name: &str) -> Option<&dyn Action> {
+        self.iter().find(|action| action.name() == name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Action> {
+        self.actions.iter().map(|action| action.as_ref())
+    }
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.iter().map(|action| action.name()))
+            .finish()
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Registry {
+        Registry::new(&Columns::default(), &Config::default())
+    }
+}
@@ -0,0 +1,33 @@
+use crate::actions::reorder::reorder_task;
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn move_task_bottom(app: &mut App) -> Result<(), String> {
+    reorder_task(app, |_, len| len - 1)
+}
+
+pub struct MoveTaskBottom;
+
+impl Action for MoveTaskBottom {
+    fn name(&self) -> &str {
+        "move_task_bottom"
+    }
+
+    fn description(&self) -> &str {
+        "Move the highlighted task to the bottom of its column"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["gJ"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        move_task_bottom(app)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::actions::reorder::reorder_task;
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn move_task_down(app: &mut App) -> Result<(), String> {
+    reorder_task(app, |from, _| from + 1)
+}
+
+pub struct MoveTaskDown;
+
+impl Action for MoveTaskDown {
+    fn name(&self) -> &str {
+        "move_task_down"
+    }
+
+    fn description(&self) -> &str {
+        "Move the highlighted task down its column"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["J"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        move_task_down(app)
+    }
+}
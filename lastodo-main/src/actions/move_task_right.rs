@@ -0,0 +1,36 @@
+use crate::actions::move_task_to::move_task_to;
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn move_task_right(app: &mut App) -> Result<(), String> {
+    let cur_task = app.cur_task.as_ref().ok_or("No task was selected.")?;
+    let status = app.columns.next(&cur_task.status);
+
+    move_task_to(app, status)
+}
+
+pub struct MoveTaskRight;
+
+impl Action for MoveTaskRight {
+    fn name(&self) -> &str {
+        "move_task_right"
+    }
+
+    fn description(&self) -> &str {
+        "Move the highlighted task to the next column"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["L"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        move_task_right(app)
+    }
+}
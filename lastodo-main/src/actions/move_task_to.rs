@@ -0,0 +1,98 @@
+use crate::actions::Action;
+use crate::app::{App, Confirmation, CurrentScreen, CurrentTask, KanbanStatus, TaskId};
+use crate::history::Change;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+/// The default keys for moving to the first nine columns
+const MOVE_BINDINGS: [&str; 9] = ["m1", "m2", "m3", "m4", "m5", "m6", "m7", "m8", "m9"];
+
+/// Move the highlighted task to the bottom of the `status` column, and follow it there. A
+/// column that's at its limit only takes it after confirming.
+pub fn move_task_to(app: &mut App, status: KanbanStatus) -> Result<(), String> {
+    let id = app.get_cur_task().ok_or("No task was selected.")?.id;
+    let (from, _) = app.locate(id).ok_or("No task was selected.")?;
+    if from == status {
+        return Err(format!("The task is already in {}.", status));
+    }
+
+    if app.is_full(&status) {
+        app.confirmation = Some(Confirmation::MoveOverLimit { id, status });
+        app.current_screen = CurrentScreen::Confirm;
+        return Ok(());
+    }
+
+    move_task(app, id, status)
+}
+
+/// Move the task to the bottom of the `status` column whatever its limit, and follow it there
+pub fn move_task(app: &mut App, id: TaskId, status: KanbanStatus) -> Result<(), String> {
+    let (from, index) = app
+        .locate(id)
+        .ok_or("The task is no longer on the board.")?;
+
+    let before = app.task_list[&from].remove(index);
+    let mut task = before.clone();
+    task.kanban_status = status.clone();
+    task.moved_at = Some(chrono::Local::now());
+    task.updated_at = task.moved_at;
+
+    let tasks = app.task_list.entry(status.clone()).or_default();
+    tasks.push(task.clone());
+    app.history.record(Change::Move {
+        before,
+        from: index,
+        after: task,
+        to: tasks.len() - 1,
+    });
+
+    app.message = format!("Moved to {}.", status);
+    app.cur_task = Some(CurrentTask {
+        status,
+        index: tasks.len() as i16 - 1,
+    });
+
+    Ok(())
+}
+
+/// Moves the highlighted task straight to one column. There is one of these per column.
+pub struct MoveTaskTo {
+    status: KanbanStatus,
+    name: String,
+    description: String,
+    binding: Option<&'static str>,
+}
+
+impl MoveTaskTo {
+    /// `position` is where the column is on the board, and decides its default keys
+    pub fn new(status: KanbanStatus, position: usize) -> MoveTaskTo {
+        MoveTaskTo {
+            name: format!("move_to_{}", status.slug()),
+            description: format!("Move the highlighted task to {}", status),
+            binding: MOVE_BINDINGS.get(position).copied(),
+            status,
+        }
+    }
+}
+
+impl Action for MoveTaskTo {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        self.binding.as_slice()
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        move_task_to(app, self.status.clone())
+    }
+}
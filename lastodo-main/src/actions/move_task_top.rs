@@ -0,0 +1,33 @@
+use crate::actions::reorder::reorder_task;
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn move_task_top(app: &mut App) -> Result<(), String> {
+    reorder_task(app, |_, _| 0)
+}
+
+pub struct MoveTaskTop;
+
+impl Action for MoveTaskTop {
+    fn name(&self) -> &str {
+        "move_task_top"
+    }
+
+    fn description(&self) -> &str {
+        "Move the highlighted task to the top of its column"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["gK"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        move_task_top(app)
+    }
+}
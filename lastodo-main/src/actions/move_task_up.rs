@@ -0,0 +1,33 @@
+use crate::actions::reorder::reorder_task;
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn move_task_up(app: &mut App) -> Result<(), String> {
+    reorder_task(app, |from, _| from.saturating_sub(1))
+}
+
+pub struct MoveTaskUp;
+
+impl Action for MoveTaskUp {
+    fn name(&self) -> &str {
+        "move_task_up"
+    }
+
+    fn description(&self) -> &str {
+        "Move the highlighted task up its column"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["K"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        move_task_up(app)
+    }
+}
@@ -1,21 +1,45 @@
+use crate::actions::Action;
 use crate::app::{App, TaskField};
+use crate::keymap::KeyContext;
 use std::result::Result;
 
 pub fn next_field(app: &mut App) -> Result<(), String> {
-    app.currently_editing_task
-        .as_mut()
-        .unwrap()
-        .currently_editing = match app
+    let state = app
         .currently_editing_task
-        .as_ref()
-        .unwrap()
-        .currently_editing
-    {
-        Some(TaskField::Title) => Some(TaskField::Description),
-        Some(TaskField::Description) => Some(TaskField::Due),
-        Some(TaskField::Due) => Some(TaskField::Title),
-        None => Some(TaskField::Title),
+        .as_mut()
+        .ok_or("No task is currently being edited.")?;
+
+    let field = match state.currently_editing {
+        Some(TaskField::Title) => TaskField::Description,
+        Some(TaskField::Description) => TaskField::Due,
+        Some(TaskField::Due) => TaskField::Title,
+        None => TaskField::Title,
     };
+    state.focus(field);
 
     Ok(())
 }
+
+pub struct NextField;
+
+impl Action for NextField {
+    fn name(&self) -> &str {
+        "next_field"
+    }
+
+    fn description(&self) -> &str {
+        "Focus the next field"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Editing
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<Tab>", "j"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        next_field(app)
+    }
+}
@@ -0,0 +1,38 @@
+use crate::actions::Action;
+use crate::app::{App, TaskEditMode};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn normal_mode(app: &mut App) -> Result<(), String> {
+    let state = app
+        .currently_editing_task
+        .as_mut()
+        .ok_or("No task is currently being edited.")?;
+    state.mode = TaskEditMode::Normal;
+
+    Ok(())
+}
+
+pub struct NormalMode;
+
+impl Action for NormalMode {
+    fn name(&self) -> &str {
+        "normal_mode"
+    }
+
+    fn description(&self) -> &str {
+        "Stop typing into the focused field"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Insert
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<Esc>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        normal_mode(app)
+    }
+}
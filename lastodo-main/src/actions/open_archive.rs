@@ -0,0 +1,35 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn open_archive(app: &mut App) -> Result<(), String> {
+    app.archive_index = app.archive.len().saturating_sub(1);
+    app.current_screen = CurrentScreen::Archive;
+
+    Ok(())
+}
+
+pub struct OpenArchive;
+
+impl Action for OpenArchive {
+    fn name(&self) -> &str {
+        "open_archive"
+    }
+
+    fn description(&self) -> &str {
+        "Browse deleted tasks"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["ga"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        open_archive(app)
+    }
+}
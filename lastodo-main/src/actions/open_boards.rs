@@ -0,0 +1,48 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn open_boards(app: &mut App) -> Result<(), String> {
+    let store = app
+        .store
+        .as_ref()
+        .ok_or("There is nowhere boards are kept.")?;
+    let boards = store
+        .boards()
+        .map_err(|err| format!("Could not list the boards: {}", err))?;
+
+    // Start on the board that's open
+    app.board_index = boards
+        .iter()
+        .position(|board| board == store.name())
+        .unwrap_or(0);
+    app.boards = boards;
+    app.current_screen = CurrentScreen::Boards;
+
+    Ok(())
+}
+
+pub struct OpenBoards;
+
+impl Action for OpenBoards {
+    fn name(&self) -> &str {
+        "open_boards"
+    }
+
+    fn description(&self) -> &str {
+        "Pick another board to work on"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["gb"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        open_boards(app)
+    }
+}
@@ -0,0 +1,34 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn palette_close(app: &mut App) -> Result<(), String> {
+    app.palette = None;
+    app.current_screen = CurrentScreen::Main;
+    Ok(())
+}
+
+pub struct PaletteClose;
+
+impl Action for PaletteClose {
+    fn name(&self) -> &str {
+        "palette_close"
+    }
+
+    fn description(&self) -> &str {
+        "Close the command palette"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Palette
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<Esc>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        palette_close(app)
+    }
+}
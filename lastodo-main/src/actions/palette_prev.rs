@@ -0,0 +1,42 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn palette_prev(app: &mut App) -> Result<(), String> {
+    let num_matches = app.palette_matches().len();
+    let palette = app
+        .palette
+        .as_mut()
+        .ok_or("The command palette is not open.")?;
+
+    if num_matches > 0 {
+        palette.selected = (palette.selected + num_matches - 1) % num_matches;
+    }
+
+    Ok(())
+}
+
+pub struct PalettePrev;
+
+impl Action for PalettePrev {
+    fn name(&self) -> &str {
+        "palette_prev"
+    }
+
+    fn description(&self) -> &str {
+        "Select the previous command"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Palette
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<Up>", "<C-p>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        palette_prev(app)
+    }
+}
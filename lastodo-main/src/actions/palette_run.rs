@@ -0,0 +1,48 @@
+use crate::actions::palette_close::palette_close;
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn palette_run(app: &mut App) -> Result<(), String> {
+    let selected = app
+        .palette
+        .as_ref()
+        .ok_or("The command palette is not open.")?
+        .selected;
+    let name = app
+        .palette_matches()
+        .get(selected)
+        .map(|(action, _)| action.name().to_string());
+
+    palette_close(app)?;
+
+    match name {
+        Some(name) => app.run_action(&name),
+        None => Err("No command matches.".to_string()),
+    }
+}
+
+pub struct PaletteRun;
+
+impl Action for PaletteRun {
+    fn name(&self) -> &str {
+        "palette_run"
+    }
+
+    fn description(&self) -> &str {
+        "Run the selected command"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Palette
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<Enter>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        palette_run(app)
+    }
+}
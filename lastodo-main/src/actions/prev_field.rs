@@ -1,21 +1,45 @@
+use crate::actions::Action;
 use crate::app::{App, TaskField};
+use crate::keymap::KeyContext;
 use std::result::Result;
 
 pub fn prev_field(app: &mut App) -> Result<(), String> {
-    app.currently_editing_task
-        .as_mut()
-        .unwrap()
-        .currently_editing = match app
+    let state = app
         .currently_editing_task
-        .as_ref()
-        .unwrap()
-        .currently_editing
-    {
-        Some(TaskField::Title) => Some(TaskField::Due),
-        Some(TaskField::Description) => Some(TaskField::Title),
-        Some(TaskField::Due) => Some(TaskField::Description),
-        None => Some(TaskField::Title),
+        .as_mut()
+        .ok_or("No task is currently being edited.")?;
+
+    let field = match state.currently_editing {
+        Some(TaskField::Title) => TaskField::Due,
+        Some(TaskField::Description) => TaskField::Title,
+        Some(TaskField::Due) => TaskField::Description,
+        None => TaskField::Title,
     };
+    state.focus(field);
 
     Ok(())
 }
+
+pub struct PrevField;
+
+impl Action for PrevField {
+    fn name(&self) -> &str {
+        "prev_field"
+    }
+
+    fn description(&self) -> &str {
+        "Focus the previous field"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Editing
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<S-Tab>", "k"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        prev_field(app)
+    }
+}
@@ -0,0 +1,33 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn quit(app: &mut App) -> Result<(), String> {
+    app.exit = true;
+    Ok(())
+}
+
+pub struct Quit;
+
+impl Action for Quit {
+    fn name(&self) -> &str {
+        "quit"
+    }
+
+    fn description(&self) -> &str {
+        "Save the board and quit"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["q"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        quit(app)
+    }
+}
@@ -1,13 +1,24 @@
+use crate::actions::Action;
 use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
 use std::result::Result;
 
 pub fn quit_editing(app: &mut App) -> Result<(), String> {
     if let Some(cur_task) = &app.currently_editing_task {
         if cur_task.has_changed {
-            app.message =
-                "You have unsaved changes. Use 'w' to save or 'x' to discard.".to_string();
+            let key = |action: &str| {
+                app.keymap
+                    .first_key(KeyContext::Editing, action)
+                    .unwrap_or_else(|| action.to_string())
+            };
+            app.message = format!(
+                "You have unsaved changes. Use '{}' to save or '{}' to discard.",
+                key("save_task"),
+                key("force_quit_editing")
+            );
         } else {
             app.current_screen = CurrentScreen::Main;
+            app.currently_editing_task = None;
         }
     } else {
         app.current_screen = CurrentScreen::Main;
@@ -15,3 +26,27 @@ pub fn quit_editing(app: &mut App) -> Result<(), String> {
 
     Ok(())
 }
+
+pub struct QuitEditing;
+
+impl Action for QuitEditing {
+    fn name(&self) -> &str {
+        "quit_editing"
+    }
+
+    fn description(&self) -> &str {
+        "Stop editing, unless there are unsaved changes"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Editing
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["q"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        quit_editing(app)
+    }
+}
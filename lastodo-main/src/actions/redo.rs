@@ -0,0 +1,32 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn redo(app: &mut App) -> Result<(), String> {
+    app.redo()
+}
+
+pub struct Redo;
+
+impl Action for Redo {
+    fn name(&self) -> &str {
+        "redo"
+    }
+
+    fn description(&self) -> &str {
+        "Redo the last change that was undone"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<C-r>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        redo(app)
+    }
+}
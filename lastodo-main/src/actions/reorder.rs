@@ -0,0 +1,46 @@
+use crate::app::App;
+use crate::history::Change;
+use crate::sort::SortMode;
+use std::result::Result;
+
+/// Move the highlighted task to `to` within its column, and keep the cursor on it. The order
+/// of a column is saved as is, so this is also its manual priority order. Columns that are
+/// sorted some other way keep their tasks in that order.
+pub fn reorder_task(app: &mut App, to: impl FnOnce(usize, usize) -> usize) -> Result<(), String> {
+    let status = &app.cur_task.as_ref().ok_or("No task was selected.")?.status;
+    let mode = app.sort_mode(status);
+    if mode != SortMode::Manual {
+        return Err(format!(
+            "{} is sorted {}, sort it by hand to move tasks in it.",
+            status,
+            mode.description()
+        ));
+    }
+
+    let cur_task = app.cur_task.as_mut().ok_or("No task was selected.")?;
+    let tasks = app
+        .task_list
+        .get_mut(&cur_task.status)
+        .ok_or("No task was selected.")?;
+
+    let from = usize::try_from(cur_task.index)
+        .ok()
+        .filter(|&index| index < tasks.len())
+        .ok_or("No task was selected.")?;
+    let to = to(from, tasks.len()).min(tasks.len() - 1);
+
+    let task = tasks.remove(from);
+    tasks.insert(to, task.clone());
+    cur_task.index = to as i16;
+
+    if from != to {
+        app.history.record(Change::Move {
+            before: task.clone(),
+            from,
+            after: task,
+            to,
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use crate::actions::Action;
+use crate::app::{App, Confirmation, CurrentScreen};
+use crate::history::Change;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+/// Restore the highlighted archived task. A column that's at its limit only takes it back
+/// after confirming.
+pub fn restore_task(app: &mut App) -> Result<(), String> {
+    let task = &app
+        .archive
+        .get(app.archive_index)
+        .ok_or("The archive is empty.")?
+        .task;
+
+    if app.is_full(&task.kanban_status) {
+        app.confirmation = Some(Confirmation::RestoreOverLimit {
+            id: task.id,
+            status: task.kanban_status.clone(),
+        });
+        app.current_screen = CurrentScreen::Confirm;
+        return Ok(());
+    }
+
+    restore(app, app.archive_index);
+
+    Ok(())
+}
+
+/// Put the task at `archive_index` in the archive back on the board, whatever its column's
+/// limit. It goes back to the bottom of the column it was deleted from.
+pub fn restore(app: &mut App, archive_index: usize) {
+    let archived = app.archive.remove(archive_index);
+    let status = archived.task.kanban_status.clone();
+    app.message = format!("Restored \"{}\" to {}.", archived.task.title, status);
+
+    let tasks = app.task_list.entry(status).or_default();
+    tasks.push(archived.task.clone());
+    let index = tasks.len() - 1;
+    app.history.record(Change::Restore {
+        archived,
+        archive_index,
+        index,
+    });
+
+    app.archive_index = app.archive_index.min(app.archive.len().saturating_sub(1));
+    app.clamp_cursor();
+}
+
+pub struct RestoreTask;
+
+impl Action for RestoreTask {
+    fn name(&self) -> &str {
+        "restore_task"
+    }
+
+    fn description(&self) -> &str {
+        "Put the highlighted archived task back on the board"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Archive
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["r"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        restore_task(app)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn save_board(app: &mut App) -> Result<(), String> {
+    app.save()
+        .map_err(|err| format!("Could not save the board: {}", err))?;
+    app.message = "Board saved.".to_string();
+
+    Ok(())
+}
+
+pub struct SaveBoard;
+
+impl Action for SaveBoard {
+    fn name(&self) -> &str {
+        "save_board"
+    }
+
+    fn description(&self) -> &str {
+        "Save the board"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["w"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        save_board(app)
+    }
+}
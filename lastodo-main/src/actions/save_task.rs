@@ -1,22 +1,104 @@
-use crate::app::App;
+use crate::actions::Action;
+use crate::app::{App, Confirmation, CurrentScreen, CurrentTask};
+use crate::history::Change;
+use crate::keymap::KeyContext;
 use std::result::Result;
 
 pub fn save_task(app: &mut App) -> Result<(), String> {
-    if app.cur_task.is_none() {
-        return Err(String::from("No task was selected."));
-    }
+    let state = app
+        .currently_editing_task
+        .as_ref()
+        .ok_or("No task is currently being edited.")?;
 
-    let status = app.cur_task.as_ref().unwrap().status;
+    if state.draft.title.trim().is_empty() {
+        return Err(String::from("A task needs a title."));
+    }
+    if let Some(err) = &state.due_error {
+        return Err(format!("Fix the due date first: {}", err));
+    }
 
-    if app.currently_editing_task.is_none() {
-        return Err(String::from("No task is currently being edited."));
+    // A column that's at its limit only takes a new task after confirming
+    let status = &state.draft.kanban_status;
+    if state.is_new_task && app.is_full(status) {
+        app.confirmation = Some(Confirmation::AddOverLimit {
+            status: status.clone(),
+        });
+        app.current_screen = CurrentScreen::Confirm;
+        return Ok(());
     }
 
-    let cur_task = app.get_cur_task().unwrap();
-    let task_list = app.task_list.entry(status.clone()).or_default();
-    let index = task_list.iter().position(|task| *task == cur_task).unwrap();
+    write_task(app)
+}
+
+/// Write the task being edited to the board, whatever its column's limit
+pub fn write_task(app: &mut App) -> Result<(), String> {
+    let state = app
+        .currently_editing_task
+        .as_ref()
+        .ok_or("No task is currently being edited.")?;
+    let mut draft = state.draft.clone();
 
-    task_list[index] = cur_task;
+    if state.is_new_task {
+        // A new task goes onto the board in one go, at the bottom of its column
+        let status = draft.kanban_status.clone();
+        let task_list = app.task_list.entry(status.clone()).or_default();
+        task_list.push(draft.clone());
+        let index = task_list.len() - 1;
+        app.cur_task = Some(CurrentTask {
+            status,
+            index: index as i16,
+        });
+        app.history.record(Change::Create {
+            task: draft.clone(),
+            index,
+        });
+        app.message = String::from("Task added.");
+    } else {
+        let (status, index) = app
+            .locate(draft.id)
+            .ok_or("The task being edited is no longer on the board.")?;
+        let task = &mut app.task_list[&status][index];
+        // Saving again without changing anything doesn't count as an update
+        if *task != draft {
+            draft.updated_at = Some(chrono::Local::now());
+        }
+        let before = std::mem::replace(task, draft.clone());
+        if before != draft {
+            app.history.record(Change::Edit {
+                before,
+                after: draft.clone(),
+            });
+        }
+        app.message = String::from("Task saved.");
+    }
+
+    let state = app.currently_editing_task.as_mut().unwrap();
+    state.draft = draft;
+    state.mark_saved();
 
     Ok(())
 }
+
+pub struct SaveTask;
+
+impl Action for SaveTask {
+    fn name(&self) -> &str {
+        "save_task"
+    }
+
+    fn description(&self) -> &str {
+        "Save the task being edited"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Editing
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["w"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        save_task(app)
+    }
+}
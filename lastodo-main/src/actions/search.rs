@@ -0,0 +1,39 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use crate::search::SearchState;
+use std::result::Result;
+
+pub fn search(app: &mut App) -> Result<(), String> {
+    app.search = Some(SearchState {
+        query: String::new(),
+        origin: app.cur_task.clone(),
+    });
+    app.current_screen = CurrentScreen::Search;
+
+    Ok(())
+}
+
+pub struct Search;
+
+impl Action for Search {
+    fn name(&self) -> &str {
+        "search"
+    }
+
+    fn description(&self) -> &str {
+        "Search the titles and descriptions of every task"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["/"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        search(app)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn search_accept(app: &mut App) -> Result<(), String> {
+    app.current_screen = CurrentScreen::Main;
+    if app.search_hits().is_empty() {
+        app.search = None;
+    }
+
+    Ok(())
+}
+
+pub struct SearchAccept;
+
+impl Action for SearchAccept {
+    fn name(&self) -> &str {
+        "search_accept"
+    }
+
+    fn description(&self) -> &str {
+        "Stay on the match and keep the search for jumping between matches"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Search
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<Enter>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        search_accept(app)
+    }
+}
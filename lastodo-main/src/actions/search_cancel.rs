@@ -0,0 +1,36 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn search_cancel(app: &mut App) -> Result<(), String> {
+    let search = app.search.take().ok_or("There is no search to cancel.")?;
+    app.cur_task = search.origin;
+    app.current_screen = CurrentScreen::Main;
+
+    Ok(())
+}
+
+pub struct SearchCancel;
+
+impl Action for SearchCancel {
+    fn name(&self) -> &str {
+        "search_cancel"
+    }
+
+    fn description(&self) -> &str {
+        "Stop searching and go back to where the search started"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Search
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<Esc>"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        search_cancel(app)
+    }
+}
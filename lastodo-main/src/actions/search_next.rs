@@ -0,0 +1,32 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn search_next(app: &mut App) -> Result<(), String> {
+    app.jump_to_next_hit(true)
+}
+
+pub struct SearchNext;
+
+impl Action for SearchNext {
+    fn name(&self) -> &str {
+        "search_next"
+    }
+
+    fn description(&self) -> &str {
+        "Jump to the next task matching the search"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["n"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        search_next(app)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn search_prev(app: &mut App) -> Result<(), String> {
+    app.jump_to_next_hit(false)
+}
+
+pub struct SearchPrev;
+
+impl Action for SearchPrev {
+    fn name(&self) -> &str {
+        "search_prev"
+    }
+
+    fn description(&self) -> &str {
+        "Jump to the previous task matching the search"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["N"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        search_prev(app)
+    }
+}
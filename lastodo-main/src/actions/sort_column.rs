@@ -0,0 +1,44 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+/// Sort the focused column the next way round, from manual through each of the others
+pub fn sort_column(app: &mut App) -> Result<(), String> {
+    let status = app
+        .cur_task
+        .as_ref()
+        .ok_or("No column was selected.")?
+        .status
+        .clone();
+    let mode = app.sort_mode(&status).next();
+
+    app.set_sort_mode(status.clone(), mode);
+    app.message = format!("{} is sorted {}.", status, mode.description());
+
+    Ok(())
+}
+
+pub struct SortColumn;
+
+impl Action for SortColumn {
+    fn name(&self) -> &str {
+        "sort_column"
+    }
+
+    fn description(&self) -> &str {
+        "Change how the highlighted column is sorted"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["s"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        sort_column(app)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::actions::Action;
+use crate::app::{App, CurrentScreen};
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn switch_board(app: &mut App) -> Result<(), String> {
+    let name = app
+        .boards
+        .get(app.board_index)
+        .cloned()
+        .ok_or("No board was selected.")?;
+    let store = app
+        .store
+        .as_ref()
+        .ok_or("There is nowhere boards are kept.")?;
+    if name == store.name() {
+        app.current_screen = CurrentScreen::Main;
+        return Ok(());
+    }
+
+    let store = store
+        .open(&name)
+        .map_err(|err| format!("Could not open {}: {}", name, err))?;
+    app.open_board(store)
+}
+
+pub struct SwitchBoard;
+
+impl Action for SwitchBoard {
+    fn name(&self) -> &str {
+        "switch_board"
+    }
+
+    fn description(&self) -> &str {
+        "Save this board and open the highlighted one"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Boards
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["<Enter>", "l"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        switch_board(app)
+    }
+}
@@ -0,0 +1,32 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::keymap::KeyContext;
+use std::result::Result;
+
+pub fn undo(app: &mut App) -> Result<(), String> {
+    app.undo()
+}
+
+pub struct Undo;
+
+impl Action for Undo {
+    fn name(&self) -> &str {
+        "undo"
+    }
+
+    fn description(&self) -> &str {
+        "Undo the last change to the board"
+    }
+
+    fn context(&self) -> KeyContext {
+        KeyContext::Main
+    }
+
+    fn default_binding(&self) -> &[&str] {
+        &["u"]
+    }
+
+    fn run(&self, app: &mut App) -> Result<(), String> {
+        undo(app)
+    }
+}
@@ -1,79 +1,213 @@
 use std::hash::Hash;
+use std::io;
+use std::rc::Rc;
 
 use chrono::DateTime;
 use color_eyre::{eyre::WrapErr, Result};
 use crossterm::event;
-use crossterm::event::{KeyCode, KeyEventKind};
+use crossterm::event::{KeyCode, KeyEventKind, KeyModifiers};
 use indexmap::IndexMap;
-
-use crate::actions;
+pub use lastodo_config::slug;
+use lastodo_config::{Config, KeyChord};
+use serde::{Deserialize, Serialize};
+use ulid::Ulid;
+
+use crate::actions::Registry;
+use crate::columns::Columns;
+use crate::dates::{self, DueInput};
+use crate::filter::Filter;
+use crate::history::History;
+use crate::keymap::{KeyContext, Keymap, Resolution};
+use crate::palette::PaletteState;
+use crate::search::SearchState;
+use crate::sort::SortMode;
+use crate::store::{Board, TaskStore};
 use crate::tui::Tui;
-use std::cmp;
 
 #[derive(Debug, Default, PartialEq)]
 pub enum CurrentScreen {
     #[default]
     Main,
     Editing,
+    Palette,
+    Confirm,
+    Archive,
+    Boards,
+    Search,
+    Filter,
 }
 
-#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
-pub enum KanbanStatus {
-    #[default]
-    Todo,
-    Partial,
-    Doing,
-    Done,
-    Blocked,
-}
+/// The column a task is in, by name. Which columns there are and what order they go in is
+/// up to the board, see [`Columns`](crate::columns::Columns).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct KanbanStatus(String);
 
 impl KanbanStatus {
-    pub fn next(&self) -> KanbanStatus {
-        match self {
-            KanbanStatus::Todo => KanbanStatus::Partial,
-            KanbanStatus::Partial => KanbanStatus::Doing,
-            KanbanStatus::Doing => KanbanStatus::Done,
-            KanbanStatus::Done => KanbanStatus::Blocked,
-            KanbanStatus::Blocked => KanbanStatus::Todo,
-        }
+    pub fn new(name: &str) -> KanbanStatus {
+        KanbanStatus(name.to_string())
     }
 
-    pub fn prev(&self) -> KanbanStatus {
-        match self {
-            KanbanStatus::Todo => KanbanStatus::Blocked,
-            KanbanStatus::Partial => KanbanStatus::Todo,
-            KanbanStatus::Doing => KanbanStatus::Partial,
-            KanbanStatus::Done => KanbanStatus::Doing,
-            KanbanStatus::Blocked => KanbanStatus::Done,
-        }
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+
+    /// The name as a [`slug`], for action names and todo.txt tags
+    pub fn slug(&self) -> String {
+        slug(&self.0)
     }
 }
 
 impl std::fmt::Display for KanbanStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            KanbanStatus::Todo => write!(f, "Todo"),
-            KanbanStatus::Partial => write!(f, "Partial"),
-            KanbanStatus::Doing => write!(f, "Doing"),
-            KanbanStatus::Done => write!(f, "Done"),
-            KanbanStatus::Blocked => write!(f, "Blocked"),
-        }
+        f.pad(&self.0)
     }
 }
 
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Eq, Clone, Debug)]
 pub enum TaskField {
     Title,
     Description,
     Due,
 }
 
-#[derive(Clone, PartialEq, Debug, Default)]
+/// Identifies a task for as long as it exists, whatever is edited about it
+pub type TaskId = Ulid;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct Task {
+    // Boards saved before tasks had IDs get them when they're loaded, for good once they're saved
+    #[serde(default = "Ulid::new")]
+    pub id: TaskId,
     pub title: String,
     pub kanban_status: KanbanStatus,
     pub description: String,
-    pub due: DateTime<chrono::Local>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<DateTime<chrono::Local>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub moved_at: Option<DateTime<chrono::Local>>, // When the task last changed columns
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<chrono::Local>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<chrono::Local>>, // When the task was last edited or moved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<String>, // `+project` in todo.txt
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contexts: Vec<String>, // `@context` in todo.txt
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "deserialize_tags"
+    )]
+    pub tags: Vec<(String, String)>, // Other `key:value` tags, in order, repeats and all
+}
+
+/// Tags as they're saved now, as a list of pairs, or as the map they used to be saved as
+fn deserialize_tags<'de, D>(deserializer: D) -> std::result::Result<Vec<(String, String)>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Tags {
+        List(Vec<(String, String)>),
+        Map(IndexMap<String, String>),
+    }
+
+    Ok(match Tags::deserialize(deserializer)? {
+        Tags::List(tags) => tags,
+        Tags::Map(tags) => tags.into_iter().collect(),
+    })
+}
+
+impl Default for Task {
+    fn default() -> Task {
+        Task {
+            id: Ulid::new(),
+            title: String::new(),
+            kanban_status: KanbanStatus::default(),
+            description: String::new(),
+            due: None,
+            moved_at: None,
+            created_at: Some(chrono::Local::now()),
+            updated_at: None,
+            priority: None,
+            projects: Vec::new(),
+            contexts: Vec::new(),
+            tags: Vec::new(),
+        }
+    }
+}
+
+/// How urgent a task is, from `A` (most) to `Z` (least), as in todo.txt
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "char", into = "char")]
+pub struct Priority(char);
+
+impl TryFrom<char> for Priority {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Priority, String> {
+        if c.is_ascii_uppercase() {
+            Ok(Priority(c))
+        } else {
+            Err(format!("`{}` is not a priority, expected A to Z", c))
+        }
+    }
+}
+
+impl From<Priority> for char {
+    fn from(priority: Priority) -> char {
+        priority.0
+    }
+}
+
+impl std::fmt::Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A task that was deleted from the board, kept so it can be restored
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ArchivedTask {
+    pub task: Task,
+    pub archived_at: DateTime<chrono::Local>,
+}
+
+/// Something that has to be confirmed before it happens
+#[derive(Clone, Debug)]
+pub enum Confirmation {
+    ArchiveTask(TaskId),
+    /// Moving a task into a column that is already at its limit
+    MoveOverLimit {
+        id: TaskId,
+        status: KanbanStatus,
+    },
+    /// Adding the task being edited to a column that is already at its limit
+    AddOverLimit {
+        status: KanbanStatus,
+    },
+    /// Restoring an archived task to a column that is already at its limit
+    RestoreOverLimit {
+        id: TaskId,
+        status: KanbanStatus,
+    },
+}
+
+impl Confirmation {
+    /// The screen it was asked from, which is where things go back to whatever the answer
+    pub fn screen(&self) -> CurrentScreen {
+        match self {
+            Confirmation::ArchiveTask(_) | Confirmation::MoveOverLimit { .. } => {
+                CurrentScreen::Main
+            }
+            Confirmation::AddOverLimit { .. } => CurrentScreen::Editing,
+            Confirmation::RestoreOverLimit { .. } => CurrentScreen::Archive,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -88,66 +222,262 @@ pub enum TaskEditMode {
     Insert,
 }
 
+/// How the due date is written when it's loaded into the editor
+const DUE_INPUT_FORMAT: &str = "%Y-%m-%d %H:%M";
+
 #[derive(Clone, Debug)]
 pub struct TaskEditState {
     pub currently_editing: Option<TaskField>,
-    pub cur_value: String,
+    pub cur_value: String, // The text of the field being edited
     pub is_new_task: bool,
     pub has_changed: bool,
     pub mode: TaskEditMode,
+    pub original: Task, // The task as it was when editing started, or when it was last saved
+    pub draft: Task,    // The task with every edit so far. The board isn't touched until a save.
+    pub due_error: Option<String>, // Why the due date typed in couldn't be used
+}
+
+impl TaskEditState {
+    pub fn new(task: Task, is_new_task: bool, mode: TaskEditMode) -> TaskEditState {
+        TaskEditState {
+            currently_editing: Some(TaskField::Title),
+            cur_value: task.title.clone(),
+            is_new_task,
+            has_changed: false,
+            mode,
+            original: task.clone(),
+            draft: task,
+            due_error: None,
+        }
+    }
+
+    /// Whether `field` in the draft differs from the original
+    pub fn is_dirty(&self, field: &TaskField) -> bool {
+        match field {
+            TaskField::Title => self.draft.title != self.original.title,
+            TaskField::Description => self.draft.description != self.original.description,
+            TaskField::Due => self.draft.due != self.original.due,
+        }
+    }
+
+    /// Move to `field`, loading its text for editing. A due date that didn't parse is dropped
+    /// on the way, leaving the last one that did.
+    pub fn focus(&mut self, field: TaskField) {
+        self.due_error = None;
+        self.cur_value = match field {
+            TaskField::Title => self.draft.title.clone(),
+            TaskField::Description => self.draft.description.clone(),
+            TaskField::Due => self
+                .draft
+                .due
+                .map(|due| due.format(DUE_INPUT_FORMAT).to_string())
+                .unwrap_or_default(),
+        };
+        self.currently_editing = Some(field);
+    }
+
+    /// Write the edited text back into the draft
+    pub fn apply_value(&mut self) {
+        match self.currently_editing {
+            Some(TaskField::Title) => self.draft.title = self.cur_value.clone(),
+            Some(TaskField::Description) => self.draft.description = self.cur_value.clone(),
+            // Invalid dates leave the draft alone, and the error is shown until the input parses
+            Some(TaskField::Due) => {
+                self.due_error = match dates::parse_due(&self.cur_value, chrono::Local::now()) {
+                    Ok(DueInput::At(due)) => {
+                        self.draft.due = Some(due);
+                        None
+                    }
+                    Ok(DueInput::Clear) => {
+                        self.draft.due = None;
+                        None
+                    }
+                    Err(err) => Some(err),
+                };
+            }
+            None => {}
+        }
+
+        self.has_changed = [TaskField::Title, TaskField::Description, TaskField::Due]
+            .iter()
+            .any(|field| self.is_dirty(field));
+    }
+
+    /// The draft has been written to the board, so it's the new baseline
+    pub fn mark_saved(&mut self) {
+        self.original = self.draft.clone();
+        self.is_new_task = false;
+        self.has_changed = false;
+    }
 }
 
 #[derive(Debug, Default)]
 pub struct App {
     pub cur_task: Option<CurrentTask>, // The currently highlighted task
     pub task_list: IndexMap<KanbanStatus, Vec<Task>>, // The list of tasks
+    pub columns: Columns,              // Which columns the board has, and what they look like
     pub current_screen: CurrentScreen, // The current screen
     pub currently_editing_task: Option<TaskEditState>, // The task currently being edited
     pub message: String,               // Status message
     pub exit: bool,                    // Whether to exit the application
+    pub store: Option<Box<dyn TaskStore>>, // Where the board is loaded from and saved to
+    pub config: Config,                // Settings from the config file
+    pub registry: Rc<Registry>,        // Every action, by name
+    pub keymap: Keymap,                // What each key does
+    pub pending_keys: Vec<KeyChord>,   // Keys typed so far of a multi-key binding
+    pub palette: Option<PaletteState>, // The command palette, while it's open
+    pub confirmation: Option<Confirmation>, // What the confirmation popup is asking about
+    pub archive: Vec<ArchivedTask>,    // Deleted tasks, oldest first
+    pub archive_index: usize,          // The highlighted task in the archive view
+    pub history: History,              // Changes to the board that can be undone
+    pub boards: Vec<String>,           // The boards to pick from in the board picker
+    pub board_index: usize,            // The highlighted board in the board picker
+    pub search: Option<SearchState>, // The search, while it's typed and while its matches are shown
+    pub filter: Option<Filter>,      // Which tasks are shown, if not all of them
+    pub filter_input: String,        // The filter being typed
+    pub sort_modes: IndexMap<KanbanStatus, SortMode>, // How the columns that aren't sorted by hand are sorted
 }
 
 impl App {
-    pub fn new() -> App {
-        let mut task_list = IndexMap::new();
-        let task1 = Task {
-            title: "Task 1".to_string(),
-            kanban_status: KanbanStatus::Todo,
-            description: "This is a task".to_string(),
-            due: chrono::Local::now(),
-        };
-        let task2 = Task {
-            title: "Task 2".to_string(),
-            kanban_status: KanbanStatus::Partial,
-            description: "This is another task".to_string(),
-            due: chrono::Local::now(),
-        };
-        let cur_task = CurrentTask {
-            status: KanbanStatus::Todo,
-            index: 0,
-        };
-        task_list.insert(KanbanStatus::Todo, vec![task1.clone()]);
-        task_list.insert(KanbanStatus::Partial, vec![task2]);
-        task_list.insert(KanbanStatus::Doing, Vec::new());
-        task_list.insert(KanbanStatus::Done, Vec::new());
-        task_list.insert(KanbanStatus::Blocked, Vec::new());
+    /// An app showing `board`, which was loaded from `store` and has `columns`
+    pub fn new(
+        store: Box<dyn TaskStore>,
+        board: Board,
+        columns: Columns,
+        config: Config,
+        registry: Rc<Registry>,
+        keymap: Keymap,
+    ) -> App {
+        let Board {
+            task_list,
+            archive,
+            sort_modes,
+            ..
+        } = board.normalize(&columns);
+        let history = History::new(config.history.depth);
+
+        // Start on the top task of the first column that has one. On an empty board the
+        // cursor still needs a column, so new tasks have somewhere to go.
+        let cur_task = task_list
+            .iter()
+            .find(|(_, tasks)| !tasks.is_empty())
+            .or_else(|| task_list.first())
+            .map(|(status, tasks)| {
+                let sort_mode = sort_modes.get(status).copied().unwrap_or_default();
+                CurrentTask {
+                    status: status.clone(),
+                    index: sort_mode
+                        .order(tasks)
+                        .first()
+                        .map_or(-1, |&index| index as i16),
+                }
+            });
 
         App {
-            cur_task: Some(cur_task),
+            cur_task,
             task_list,
+            columns,
             current_screen: CurrentScreen::Main,
             currently_editing_task: None,
             message: String::new(),
             exit: false,
+            store: Some(store),
+            config,
+            registry,
+            keymap,
+            pending_keys: Vec::new(),
+            palette: None,
+            confirmation: None,
+            archive,
+            archive_index: 0,
+            history,
+            boards: Vec::new(),
+            board_index: 0,
+            search: None,
+            filter: None,
+            filter_input: String::new(),
+            sort_modes,
         }
     }
 
+    /// The name of the board being shown
+    pub fn board_name(&self) -> &str {
+        self.store.as_ref().map_or("Lastodo", |store| store.name())
+    }
+
+    /// Save this board and show the one in `store` instead. It has its own columns, and so its
+    /// own actions and keys, and its own history.
+    pub fn open_board(&mut self, store: Box<dyn TaskStore>) -> std::result::Result<(), String> {
+        let name = store.name().to_string();
+        self.save()
+            .map_err(|err| format!("Could not save the board: {}", err))?;
+
+        let board = store
+            .load()
+            .map_err(|err| format!("Could not load {}: {}", name, err))?;
+        let columns = Columns::for_board(&board, &self.config);
+        let registry = Rc::new(Registry::new(&columns, &self.config));
+        let keymap = Keymap::from_config(&self.config, &registry)
+            .map_err(|err| format!("Could not open {}: {}", name, err))?;
+
+        *self = App::new(store, board, columns, self.config.clone(), registry, keymap);
+        self.message = format!("Switched to {}.", name);
+
+        Ok(())
+    }
+
+    /// Find the column and position of the task with `id`
+    pub fn locate(&self, id: TaskId) -> Option<(KanbanStatus, usize)> {
+        self.task_list.iter().find_map(|(status, tasks)| {
+            let index = tasks.iter().position(|task| task.id == id)?;
+            Some((status.clone(), index))
+        })
+    }
+
+    /// Whether the `status` column is at its limit, so another task would take it over
+    pub fn is_full(&self, status: &KanbanStatus) -> bool {
+        let count = self.task_list.get(status).map_or(0, Vec::len);
+        self.columns
+            .limit(status)
+            .is_some_and(|limit| count >= limit)
+    }
+
+    /// Keep the cursor in its column after tasks were taken out of it
+    pub fn clamp_cursor(&mut self) {
+        if let Some(cur_task) = self.cur_task.as_mut() {
+            let num_tasks = self.task_list.get(&cur_task.status).map_or(0, Vec::len) as i16;
+            cur_task.index = if num_tasks == 0 {
+                -1
+            } else {
+                cur_task.index.clamp(0, num_tasks - 1)
+            };
+        }
+    }
+
+    /// Write the board to the store, if there is one
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(store) = &self.store {
+            store.save(&Board {
+                task_list: self.task_list.clone(),
+                archive: self.archive.clone(),
+                columns: self.columns.own().cloned(),
+                sort_modes: self.sort_modes.clone(),
+            })?;
+        }
+
+        Ok(())
+    }
+
     pub fn get_cur_task(&self) -> Option<Task> {
         self.cur_task.as_ref()?;
 
         let cur_task_status = self.cur_task.as_ref().unwrap().status.clone();
-        let cur_task_index = self.cur_task.as_ref().unwrap().index as usize;
-        let cur_task = self.task_list[&cur_task_status][cur_task_index].clone();
+        let cur_task_index = usize::try_from(self.cur_task.as_ref().unwrap().index).ok()?;
+        let cur_task = self
+            .task_list
+            .get(&cur_task_status)?
+            .get(cur_task_index)?
+            .clone();
 
         Some(cur_task)
     }
@@ -158,209 +488,184 @@ impl App {
             self.handle_events().wrap_err("handle events failed")?;
         }
 
+        self.save().wrap_err("saving the board failed")?;
+
         Ok(())
     }
 
-    fn handle_normal_mode(&mut self, key_code: KeyCode) {
-        match key_code {
-            KeyCode::Char('i') => {
-                self.currently_editing_task.as_mut().unwrap().mode = TaskEditMode::Insert;
-            }
-            KeyCode::Char('w') => {
-                actions::save_task::save_task(self);
-            }
-            KeyCode::Char('q') => {
-                actions::quit_editing::quit_editing(self);
-            }
-            KeyCode::Char('x') => {
-                actions::force_quit_editing::force_quit_editing(self);
-            }
-            KeyCode::Tab => {
-                actions::next_field::next_field(self);
-            }
-            KeyCode::BackTab => {
-                actions::prev_field::prev_field(self);
-            }
-            _ => {}
+    /// Which key bindings apply right now
+    fn key_context(&self) -> KeyContext {
+        match (&self.current_screen, &self.currently_editing_task) {
+            (CurrentScreen::Palette, _) => KeyContext::Palette,
+            (CurrentScreen::Confirm, _) => KeyContext::Confirm,
+            (CurrentScreen::Archive, _) => KeyContext::Archive,
+            (CurrentScreen::Boards, _) => KeyContext::Boards,
+            (CurrentScreen::Search, _) => KeyContext::Search,
+            (CurrentScreen::Filter, _) => KeyContext::Filter,
+            (CurrentScreen::Editing, Some(state)) => match state.mode {
+                TaskEditMode::Normal => KeyContext::Editing,
+                TaskEditMode::Insert => KeyContext::Insert,
+            },
+            _ => KeyContext::Main,
         }
     }
 
-    fn handle_insert_mode(&mut self, key_code: KeyCode) {
-        match key_code {
-            KeyCode::Esc => {
-                self.currently_editing_task.as_mut().unwrap().mode = TaskEditMode::Normal;
+    /// Run the action called `name`
+    pub fn run_action(&mut self, name: &str) -> std::result::Result<(), String> {
+        let registry = Rc::clone(&self.registry);
+        let action = registry
+            .get(name)
+            .ok_or_else(|| format!("Unknown action: {}", name))?;
+
+        action.run(self)
+    }
+
+    /// Run the action a key is bound to, showing its error in the status bar if it fails
+    fn dispatch(&mut self, name: &str) {
+        if let Err(err) = self.run_action(name) {
+            self.message = err;
+        }
+    }
+
+    fn handle_key(&mut self, key: KeyChord) {
+        let context = self.key_context();
+        self.pending_keys.push(key);
+
+        match self.keymap.resolve(context, &self.pending_keys) {
+            Resolution::Pending => {}
+            Resolution::Action(name) => {
+                self.pending_keys.clear();
+                self.dispatch(&name);
             }
-            KeyCode::Backspace => {
-                if let Some(field) = &self.currently_editing_task.as_mut() {
-                    let cur_task_status = self.cur_task.as_ref().unwrap().status.clone();
-                    let cur_task_index = self.cur_task.as_ref().unwrap().index as usize;
+            Resolution::Unbound => {
+                let mut pending = std::mem::take(&mut self.pending_keys);
+                let key = pending.pop().unwrap();
 
-                    match field.currently_editing {
-                        Some(TaskField::Title) => {
-                            self.task_list[&cur_task_status][cur_task_index].title.pop();
-                        }
-                        Some(TaskField::Description) => {
-                            self.task_list[&cur_task_status][cur_task_index]
-                                .description
-                                .pop();
-                        }
-                        Some(TaskField::Due) => {}
-                        None => {}
-                    }
+                if pending.is_empty() {
+                    self.handle_input(context, key);
+                    return;
                 }
-            }
-            KeyCode::Char(val) => {
-                if let Some(field) = &self.currently_editing_task.as_mut() {
-                    let cur_task_status = self.cur_task.as_ref().unwrap().status.clone();
-                    let cur_task_index = self.cur_task.as_ref().unwrap().index as usize;
-
-                    match field.currently_editing {
-                        Some(TaskField::Title) => {
-                            self.task_list[&cur_task_status][cur_task_index]
-                                .title
-                                .push(val);
-                        }
-                        Some(TaskField::Description) => {
-                            self.task_list[&cur_task_status][cur_task_index]
-                                .description
-                                .push(val);
-                        }
-                        Some(TaskField::Due) => {
-                            // TODO: Fix later
-                            self.task_list[&cur_task_status][cur_task_index].due =
-                                chrono::Local::now();
+
+                // The earlier keys might be a complete binding that was waiting to see if a
+                // longer one was being typed, like `g` when `gg` is also bound. If they aren't,
+                // they were typed text, like the `j` of `ja` when `jk` leaves insert mode.
+                match self.keymap.resolve(context, &pending) {
+                    Resolution::Action(name) => self.dispatch(&name),
+                    _ => {
+                        for pending_key in pending {
+                            self.handle_input(context, pending_key);
                         }
-                        _ => {}
                     }
                 }
+                self.handle_key(key);
+            }
+        }
+    }
+
+    /// Type a key that isn't bound to anything into whatever is being typed into, if anything
+    fn handle_input(&mut self, context: KeyContext, key: KeyChord) {
+        match context {
+            KeyContext::Insert => self.handle_text_input(key),
+            KeyContext::Palette => self.handle_palette_input(key),
+            KeyContext::Search => self.handle_search_input(key),
+            KeyContext::Filter => self.handle_filter_input(key),
+            _ => {}
+        }
+    }
+
+    /// Type into the command palette
+    fn handle_palette_input(&mut self, key: KeyChord) {
+        let Some(palette) = self.palette.as_mut() else {
+            return;
+        };
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Backspace => {
+                palette.query.pop();
+            }
+            KeyCode::Char(val) => palette.query.push(val),
+            _ => return,
+        }
+        palette.selected = 0;
+    }
+
+    /// Type into the search, moving the cursor to the best match as the query changes
+    fn handle_search_input(&mut self, key: KeyChord) {
+        let Some(search) = self.search.as_mut() else {
+            return;
+        };
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Backspace => {
+                search.query.pop();
             }
+            KeyCode::Char(val) => search.query.push(val),
+            _ => return,
+        }
+        self.jump_to_best_hit();
+    }
+
+    /// Type into the filter
+    fn handle_filter_input(&mut self, key: KeyChord) {
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return;
+        }
+
+        match key.code {
+            KeyCode::Backspace => {
+                self.filter_input.pop();
+            }
+            KeyCode::Char(val) => self.filter_input.push(val),
             _ => {}
         }
     }
 
+    /// Type into the field being edited
+    fn handle_text_input(&mut self, key: KeyChord) {
+        if key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT)
+        {
+            return;
+        }
+
+        let Some(state) = self.currently_editing_task.as_mut() else {
+            return;
+        };
+
+        match key.code {
+            KeyCode::Backspace => {
+                state.cur_value.pop();
+            }
+            KeyCode::Char(val) => state.cur_value.push(val),
+            _ => return,
+        }
+        state.apply_value();
+    }
+
     fn handle_events(&mut self) -> std::io::Result<()> {
         match event::read()? {
             event::Event::Key(key_event) if key_event.kind == KeyEventKind::Press => {
-                match self.current_screen {
-                    CurrentScreen::Editing => {
-                        match self.currently_editing_task.as_ref().unwrap().mode {
-                            TaskEditMode::Normal => self.handle_normal_mode(key_event.code),
-                            TaskEditMode::Insert => self.handle_insert_mode(key_event.code),
-                        }
-                    }
-                    CurrentScreen::Main => match key_event.code {
-                        KeyCode::Char('q') => {
-                            self.exit = true;
-                        }
-                        KeyCode::Char('w') => {
-                            // TODO
-                        }
-                        KeyCode::Char('h') => {
-                            if let Some(cur_task) = &self.cur_task {
-                                let cur_task_status = cur_task.status.clone();
-                                let cur_task_index = cur_task.index;
-
-                                let new_status = cur_task_status.prev();
-                                let new_idx = cmp::min(
-                                    cur_task_index,
-                                    self.task_list[&new_status].len() as i16 - 1,
-                                );
-
-                                if new_idx == -1 {
-                                    return Ok(());
-                                }
-
-                                self.cur_task = Some(CurrentTask {
-                                    status: new_status,
-                                    index: new_idx,
-                                });
-                            }
-                        }
-                        KeyCode::Char('l') => {
-                            if let Some(cur_task) = &self.cur_task {
-                                let cur_task_status = cur_task.status.clone();
-                                let cur_task_index = cur_task.index;
-
-                                let new_status = cur_task_status.next();
-                                let new_idx = cmp::min(
-                                    cur_task_index,
-                                    self.task_list[&new_status].len() as i16 - 1,
-                                );
-
-                                if new_idx == -1 {
-                                    return Ok(());
-                                }
-
-                                self.cur_task = Some(CurrentTask {
-                                    status: new_status,
-                                    index: new_idx,
-                                });
-                            }
-                        }
-                        KeyCode::Char('j') => {
-                            /* Yes, I know j moves down in vim. I prefer it the other way around.
-                             * It's my app, my rules. (But I'll probably make this configurable) */
-                            if let Some(cur_task) = &self.cur_task {
-                                let cur_task_status = cur_task.status.clone();
-                                let cur_task_index = cur_task.index;
-                                let num_tasks = self.task_list[&cur_task_status].len() as i16;
-
-                                let new_idx = (cur_task_index - 1) % num_tasks;
-                                let new_status = cur_task_status;
-
-                                if new_idx >= self.task_list[&new_status].len() as i16 {
-                                    return Ok(());
-                                }
-
-                                self.cur_task = Some(CurrentTask {
-                                    status: new_status,
-                                    index: new_idx,
-                                });
-                            }
-                        }
-                        KeyCode::Char('k') => {
-                            if let Some(cur_task) = &self.cur_task {
-                                let cur_task_status = cur_task.status.clone();
-                                let cur_task_index = cur_task.index;
-                                let num_tasks = self.task_list[&cur_task_status].len() as i16;
-
-                                let new_idx = (cur_task_index + 1) % num_tasks;
-                                let new_status = cur_task_status;
-
-                                if new_idx >= self.task_list[&new_status].len() as i16 {
-                                    return Ok(());
-                                }
-
-                                self.cur_task = Some(CurrentTask {
-                                    status: new_status,
-                                    index: new_idx,
-                                });
-                            }
-                        }
-                        KeyCode::Char('i') => {
-                            self.current_screen = CurrentScreen::Editing;
-
-                            self.currently_editing_task =
-                                if let Some(cur_task) = self.get_cur_task() {
-                                    Some(TaskEditState {
-                                        currently_editing: Some(TaskField::Title),
-                                        cur_value: cur_task.title,
-                                        is_new_task: false,
-                                        has_changed: false,
-                                        mode: TaskEditMode::Normal,
-                                    })
-                                } else {
-                                    Some(TaskEditState {
-                                        currently_editing: Some(TaskField::Title),
-                                        cur_value: String::new(),
-                                        is_new_task: true,
-                                        has_changed: false,
-                                        mode: TaskEditMode::Normal,
-                                    })
-                                }
-                        }
-                        _ => {}
-                    },
+                self.message.clear();
+                self.handle_key(KeyChord::from(key_event));
+                // Whatever the key did, the cursor shouldn't end up on a hidden task
+                if self.filter.is_some() {
+                    self.keep_cursor_visible();
                 }
             }
             _ => {}
@@ -0,0 +1,415 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::{Parser, Subcommand, ValueEnum};
+use lastodo_config::Config;
+
+use crate::actions::Registry;
+use crate::app::{KanbanStatus, Task};
+use crate::columns::Columns;
+use crate::dates::{self, DueInput};
+use crate::filter::Filter;
+use crate::formats::BoardFormat;
+use crate::keymap::Keymap;
+use crate::store::file_store::PROJECT_DIR_NAME;
+use crate::store::{Board, FileStore, TaskStore};
+
+/// A Kanban board for your terminal
+#[derive(Debug, Parser)]
+#[command(name = "lastodo", version)]
+pub struct Cli {
+    /// Use this config file instead of ~/.config/lastodo/config.toml
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Open this board instead of the default one. It's created if it doesn't exist yet.
+    #[arg(long, short, global = true, value_name = "NAME")]
+    pub board: Option<String>,
+
+    /// Use the global boards, even in a project that has its own
+    #[arg(long, global = true)]
+    pub global: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Add a task to the board
+    Add {
+        title: String,
+        /// When the task is due, e.g. `tomorrow 9:00`, `fri` or `2024-06-01`
+        #[arg(long)]
+        due: Option<String>,
+        /// The column to add the task to. Defaults to the first one.
+        #[arg(long)]
+        status: Option<String>,
+        #[arg(long, default_value = "")]
+        description: String,
+        /// Add the task even if the column is at its limit
+        #[arg(long)]
+        force: bool,
+    },
+    /// List the tasks on the board
+    List {
+        /// Only list the tasks in this column
+        #[arg(long)]
+        status: Option<String>,
+        /// Only list the tasks matching this query, like `due:<7d status:!done`, or the filter
+        /// with this name in the config
+        #[arg(long, value_name = "QUERY", allow_hyphen_values = true)]
+        filter: Option<String>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Move a task to another column
+    Move {
+        /// The task's ID, or enough of the start of it to tell it apart
+        id: String,
+        status: String,
+        /// Move the task even if the column is at its limit
+        #[arg(long)]
+        force: bool,
+    },
+    /// Show everything about a task
+    Show {
+        /// The task's ID, or enough of the start of it to tell it apart
+        id: String,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Write out the whole board, including the archive
+    Export {
+        #[arg(long, value_enum, default_value_t = BoardFormat::Json)]
+        format: BoardFormat,
+        /// Write to this file instead of standard output
+        #[arg(long, short, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+    /// Merge an exported board into this one, matching tasks by ID
+    Import {
+        path: PathBuf,
+        /// The format of the file. Guessed from its extension if left out.
+        #[arg(long, value_enum)]
+        format: Option<BoardFormat>,
+        /// Replace tasks that differ from the imported ones, instead of reporting them
+        #[arg(long)]
+        overwrite: bool,
+    },
+    /// Keep boards for the project in the current directory, in a `.lastodo` directory that
+    /// can be committed along with it
+    Init,
+    /// Work with the config file
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigCommand {
+    /// Check the config file for errors
+    Check,
+}
+
+/// How tasks are printed
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+/// Create the project directory in the current directory
+pub fn init() -> Result<(), String> {
+    let dir = env::current_dir()
+        .map_err(|err| format!("could not find the current directory: {}", err))?;
+    FileStore::init(&dir).map_err(|err| err.to_string())?;
+    println!(
+        "Created an empty board in {}.",
+        dir.join(PROJECT_DIR_NAME).display()
+    );
+
+    Ok(())
+}
+
+/// Validate the config at `path`, printing what was found. Returns whether it was valid.
+pub fn check_config(path: &Path) -> bool {
+    if !path.exists() {
+        println!("No config file at {}, using the defaults.", path.display());
+        return true;
+    }
+
+    // Actions for moving to a board's own columns can't be checked without the board, and
+    // neither can filters on them, so this checks against the config's columns
+    let result = Config::load(path).and_then(|config| {
+        let columns = Columns::new(&config.columns);
+        let registry = Registry::new(&columns, &config);
+        Keymap::from_config(&config, &registry)?;
+        for query in config.filters.values() {
+            Filter::parse(query.get_ref(), &columns, chrono::Local::now())
+                .map_err(|err| config.error_at(query.span(), err))?;
+        }
+        Ok(())
+    });
+    match result {
+        Ok(_) => {
+            println!("{}: OK", path.display());
+            true
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            false
+        }
+    }
+}
+
+/// Refuse to put another task into a column that is at its limit, unless it's forced
+fn check_limit(
+    board: &Board,
+    columns: &Columns,
+    status: &KanbanStatus,
+    force: bool,
+) -> Result<(), String> {
+    let count = board.task_list.get(status).map_or(0, Vec::len);
+    match columns.limit(status) {
+        Some(limit) if count >= limit && !force => Err(format!(
+            "{} is at its limit of {} tasks, use --force to go over it",
+            status, limit
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Run one of the commands that work on the board without opening the TUI
+pub fn run(command: &Command, store: &dyn TaskStore, config: &Config) -> Result<(), String> {
+    let board = store.load().map_err(|err| err.to_string())?;
+    let columns = Columns::for_board(&board, config);
+    let mut board = board.normalize(&columns);
+    let date_format = config.ui.date_format.as_str();
+
+    match command {
+        Command::Add {
+            title,
+            due,
+            status,
+            description,
+            force,
+        } => {
+            if title.trim().is_empty() {
+                return Err(String::from("a task needs a title"));
+            }
+            let due = match due {
+                Some(due) => match dates::parse_due(due, chrono::Local::now())? {
+                    DueInput::At(due) => Some(due),
+                    DueInput::Clear => None,
+                },
+                None => None,
+            };
+
+            let status = match status {
+                Some(status) => columns.find(status)?,
+                None => columns.first().clone(),
+            };
+            check_limit(&board, &columns, &status, *force)?;
+            let task = Task {
+                title: title.clone(),
+                kanban_status: status.clone(),
+                description: description.clone(),
+                due,
+                ..Task::default()
+            };
+            println!("{}", task.id);
+            board
+                .task_list
+                .entry(status.clone())
+                .or_default()
+                .push(task);
+            store.save(&board).map_err(|err| err.to_string())?;
+        }
+        Command::List {
+            status,
+            filter,
+            format,
+        } => {
+            let status = status
+                .as_deref()
+                .map(|status| columns.find(status))
+                .transpose()?;
+            let filter = filter
+                .as_deref()
+                .map(|query| {
+                    let query = config
+                        .filters
+                        .get(query)
+                        .map_or(query, |saved| saved.get_ref());
+                    Filter::parse(query, &columns, chrono::Local::now())
+                })
+                .transpose()?;
+            // Each column is listed the way it's sorted on the board
+            let tasks: Vec<&Task> = board
+                .task_list
+                .iter()
+                .filter(|(column, _)| status.as_ref().is_none_or(|status| status == *column))
+                .flat_map(|(column, tasks)| {
+                    let sort_mode = board.sort_modes.get(column).copied().unwrap_or_default();
+                    sort_mode
+                        .order(tasks)
+                        .into_iter()
+                        .map(|index| &tasks[index])
+                })
+                .filter(|task| filter.as_ref().is_none_or(|filter| filter.matches(task)))
+                .collect();
+
+            match format {
+                Format::Json => print_json(&tasks)?,
+                Format::Text => {
+                    for task in tasks {
+                        let due = task.due.map_or_else(
+                            || String::from("-"),
+                            |due| due.format(date_format).to_string(),
+                        );
+                        println!(
+                            "{}  {:<8} {:<12} {}",
+                            task.id,
+                            task.kanban_status.to_string(),
+                            due,
+                            task.title
+                        );
+                    }
+                }
+            }
+        }
+        Command::Move { id, status, force } => {
+            let status = columns.find(status)?;
+            let (from, index) = board.find(id)?;
+            if from == status {
+                return Err(format!("the task is already in {}", status));
+            }
+            check_limit(&board, &columns, &status, *force)?;
+
+            // Same as moving it on the board: it goes to the bottom of the new column
+            let mut task = board.task_list[&from].remove(index);
+            task.kanban_status = status.clone();
+            task.moved_at = Some(chrono::Local::now());
+            task.updated_at = task.moved_at;
+            println!("Moved \"{}\" to {}.", task.title, status);
+            board.task_list.entry(status).or_default().push(task);
+            store.save(&board).map_err(|err| err.to_string())?;
+        }
+        Command::Show { id, format } => {
+            let (status, index) = board.find(id)?;
+            let task = &board.task_list[&status][index];
+
+            match format {
+                Format::Json => print_json(task)?,
+                Format::Text => {
+                    println!("ID:       {}", task.id);
+                    println!("Title:    {}", task.title);
+                    println!("Status:   {}", task.kanban_status);
+                    if let Some(priority) = task.priority {
+                        println!("Priority: {}", priority);
+                    }
+                    if let Some(due) = task.due {
+                        println!("Due:      {}", due.format(date_format));
+                    }
+                    if !task.projects.is_empty() {
+                        println!("Projects: {}", joined(&task.projects, "+"));
+                    }
+                    if !task.contexts.is_empty() {
+                        println!("Contexts: {}", joined(&task.contexts, "@"));
+                    }
+                    if !task.tags.is_empty() {
+                        let tags: Vec<String> = task
+                            .tags
+                            .iter()
+                            .map(|(key, value)| format!("{}:{}", key, value))
+                            .collect();
+                        println!("Tags:     {}", tags.join(" "));
+                    }
+                    if let Some(created_at) = task.created_at {
+                        println!("Created:  {}", created_at.format(date_format));
+                    }
+                    if let Some(updated_at) = task.updated_at {
+                        println!("Updated:  {}", updated_at.format(date_format));
+                    }
+                    if let Some(moved_at) = task.moved_at {
+                        println!("Moved:    {}", moved_at.format(date_format));
+                    }
+                    if !task.description.is_empty() {
+                        println!("\n{}", task.description);
+                    }
+                }
+            }
+        }
+        Command::Export { format, output } => {
+            let exported = format.export(&board, &columns)?;
+            match output {
+                Some(path) => fs::write(path, exported)
+                    .map_err(|err| format!("could not write {}: {}", path.display(), err))?,
+                None => print!("{}", exported),
+            }
+        }
+        Command::Import {
+            path,
+            format,
+            overwrite,
+        } => {
+            let format = format
+                .or_else(|| BoardFormat::from_path(path))
+                .ok_or("could not tell the format from the file name, use --format")?;
+            let input = fs::read_to_string(path)
+                .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+            let imported = format
+                .import(&input, &columns)
+                .map_err(|err| format!("{}: {}", path.display(), err))?;
+
+            let report = board.merge(imported, *overwrite, |ours, theirs| {
+                format.reconcile(ours, theirs, &columns)
+            });
+            store.save(&board).map_err(|err| err.to_string())?;
+
+            println!(
+                "{} added, {} unchanged, {} overwritten, {} conflicting",
+                report.added,
+                report.unchanged,
+                report.overwritten,
+                report.conflicts.len()
+            );
+            for conflict in &report.conflicts {
+                println!(
+                    "conflict: {} \"{}\" differs in {}",
+                    conflict.id,
+                    conflict.title,
+                    conflict.fields.join(", ")
+                );
+            }
+            if !report.conflicts.is_empty() {
+                return Err(String::from(
+                    "conflicting tasks were left as they are, use --overwrite to replace them",
+                ));
+            }
+        }
+        Command::Init | Command::Config { .. } => {
+            unreachable!("init and config commands run before the config is loaded")
+        }
+    }
+
+    Ok(())
+}
+
+fn print_json(value: &impl serde::Serialize) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(value).map_err(|err| err.to_string())?;
+    println!("{}", json);
+    Ok(())
+}
+
+/// `names` written the way todo.txt does, each after `sigil`, as in `+home +garden`
+fn joined(names: &[String], sigil: &str) -> String {
+    names
+        .iter()
+        .map(|name| format!("{}{}", sigil, name))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,138 @@
+use lastodo_config::{Color, ColumnList, Config};
+
+use crate::app::KanbanStatus;
+use crate::store::Board;
+
+/// One column of the board
+#[derive(Clone, Debug)]
+pub struct Column {
+    pub status: KanbanStatus,
+    pub color: Option<Color>,
+    pub done: bool,
+    pub limit: Option<usize>,
+}
+
+/// The columns a board is shown with, from left to right. They come from the board if it
+/// defines its own, or else from the config. Tasks can also be in columns that aren't defined
+/// anymore, and those columns are added at the end so the tasks don't go missing.
+#[derive(Clone, Debug)]
+pub struct Columns {
+    columns: Vec<Column>,
+    /// The board's own definition, which is saved with it
+    own: Option<ColumnList>,
+}
+
+impl Columns {
+    pub fn new(columns: &ColumnList) -> Columns {
+        Columns {
+            columns: columns
+                .iter()
+                .map(|column| Column {
+                    status: KanbanStatus::new(&column.name),
+                    color: column.color,
+                    done: column.done,
+                    limit: column.limit,
+                })
+                .collect(),
+            own: None,
+        }
+    }
+
+    pub fn for_board(board: &Board, config: &Config) -> Columns {
+        let mut columns = Columns::new(board.columns.as_ref().unwrap_or(&config.columns));
+        columns.own = board.columns.clone();
+
+        for status in board.task_list.keys() {
+            if columns.get(status).is_none() {
+                columns.columns.push(Column {
+                    status: status.clone(),
+                    color: None,
+                    done: false,
+                    limit: None,
+                });
+            }
+        }
+
+        columns
+    }
+
+    /// The board's own column definition, if it has one instead of using the config's
+    pub fn own(&self) -> Option<&ColumnList> {
+        self.own.as_ref()
+    }
+
+    pub fn statuses(&self) -> impl Iterator<Item = &KanbanStatus> {
+        self.columns.iter().map(|column| &column.status)
+    }
+
+    pub fn get(&self, status: &KanbanStatus) -> Option<&Column> {
+        self.columns.iter().find(|column| column.status == *status)
+    }
+
+    /// The leftmost column, where tasks go unless they're told otherwise
+    pub fn first(&self) -> &KanbanStatus {
+        &self.columns[0].status
+    }
+
+    /// The column that means a task is finished, if there is one
+    pub fn done(&self) -> Option<&KanbanStatus> {
+        self.columns
+            .iter()
+            .find(|column| column.done)
+            .map(|column| &column.status)
+    }
+
+    pub fn is_done(&self, status: &KanbanStatus) -> bool {
+        self.get(status).is_some_and(|column| column.done)
+    }
+
+    /// How many tasks `status` should hold at most, if it has a limit
+    pub fn limit(&self, status: &KanbanStatus) -> Option<usize> {
+        self.get(status).and_then(|column| column.limit)
+    }
+
+    /// The column to the right of `status`, going round to the first after the last
+    pub fn next(&self, status: &KanbanStatus) -> KanbanStatus {
+        let position = self.position(status);
+        self.columns[(position + 1) % self.columns.len()]
+            .status
+            .clone()
+    }
+
+    /// The column to the left of `status`, going round to the last before the first
+    pub fn prev(&self, status: &KanbanStatus) -> KanbanStatus {
+        let position = self.position(status);
+        self.columns[(position + self.columns.len() - 1) % self.columns.len()]
+            .status
+            .clone()
+    }
+
+    /// The column called `name`, ignoring case, or whose slug is `name`
+    pub fn find(&self, name: &str) -> Result<KanbanStatus, String> {
+        let name = name.trim();
+        self.statuses()
+            .find(|status| status.name().eq_ignore_ascii_case(name) || status.slug() == name)
+            .cloned()
+            .ok_or_else(|| {
+                let names: Vec<&str> = self.statuses().map(|status| status.name()).collect();
+                format!(
+                    "unknown column `{}`, expected one of {}",
+                    name,
+                    names.join(", ")
+                )
+            })
+    }
+
+    fn position(&self, status: &KanbanStatus) -> usize {
+        self.columns
+            .iter()
+            .position(|column| column.status == *status)
+            .unwrap_or(0)
+    }
+}
+
+impl Default for Columns {
+    fn default() -> Columns {
+        Columns::new(&ColumnList::default())
+    }
+}
@@ -0,0 +1,314 @@
+use chrono::{
+    DateTime, Datelike, Days, Duration, Local, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    TimeZone, Weekday,
+};
+
+/// What the user asked the due date to be
+#[derive(Clone, Debug, PartialEq)]
+pub enum DueInput {
+    At(DateTime<Local>),
+    Clear,
+}
+
+/// Parse a due date typed by the user, relative to `now`. Understands:
+///
+/// - ISO dates and times: `2024-06-01`, `2024-06-01 14:30`, `2024-06-01T14:30:00+02:00`
+/// - A time on its own, meaning today: `14:30`
+/// - `today`, `tomorrow`, `yesterday`, optionally followed by a time: `tomorrow 9:00`
+/// - Weekdays: `fri` is the next Friday after today, `next fri` is the Friday of next week
+/// - Offsets: `in 3d`, `2w`, `in 5h`, `30min`, `in 1mo`
+/// - Ends of periods: `eod`, `eow` (Sunday night) and `eom`
+/// - `none`, `clear`, `-` or nothing at all to remove the due date
+///
+/// Dates without a time are due at midnight at the start of that day.
+pub fn parse_due(input: &str, now: DateTime<Local>) -> Result<DueInput, String> {
+    let input = input.trim().to_lowercase();
+    if matches!(input.as_str(), "" | "none" | "clear" | "-") {
+        return Ok(DueInput::Clear);
+    }
+
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(&input.to_uppercase()) {
+        return Ok(DueInput::At(datetime.with_timezone(&Local)));
+    }
+    for format in [
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dt%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dt%H:%M:%S",
+    ] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(&input, format) {
+            return local(datetime).map(DueInput::At);
+        }
+    }
+
+    if let Some(datetime) = parse_offset(&input, now)? {
+        return Ok(DueInput::At(datetime));
+    }
+
+    let today = now.date_naive();
+    let end_of_day = NaiveTime::from_hms_opt(23, 59, 59).unwrap();
+    match input.as_str() {
+        "eod" => return local(today.and_time(end_of_day)).map(DueInput::At),
+        "eow" => {
+            let days_left = 6 - today.weekday().num_days_from_monday();
+            let sunday = today + Days::new(days_left as u64);
+            return local(sunday.and_time(end_of_day)).map(DueInput::At);
+        }
+        "eom" => {
+            let first_of_next_month = today.with_day(1).unwrap() + Months::new(1);
+            let last_of_month = first_of_next_month.pred_opt().unwrap();
+            return local(last_of_month.and_time(end_of_day)).map(DueInput::At);
+        }
+        _ => {}
+    }
+
+    // Everything else is a day, optionally followed by a time
+    let (day_part, time) = match input.rsplit_once(' ') {
+        Some((day_part, time_part)) => match parse_time(time_part) {
+            Some(time) => (day_part, Some(time)),
+            None => (input.as_str(), None),
+        },
+        None => match parse_time(&input) {
+            Some(time) => return local(today.and_time(time)).map(DueInput::At),
+            None => (input.as_str(), None),
+        },
+    };
+
+    let date = parse_day(day_part.trim(), today)
+        .ok_or_else(|| format!("Don't know what date \"{}\" is", input))?;
+    local(date.and_time(time.unwrap_or(NaiveTime::MIN))).map(DueInput::At)
+}
+
+/// `in 3d`, `3d`, `in 2 weeks` and so on
+fn parse_offset(input: &str, now: DateTime<Local>) -> Result<Option<DateTime<Local>>, String> {
+    let offset = input.strip_prefix("in ").unwrap_or(input).replace(' ', "");
+    let digits_end = offset
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(offset.len());
+    let (amount, unit) = offset.split_at(digits_end);
+
+    let Ok(amount) = amount.parse::<u32>() else {
+        return Ok(None);
+    };
+
+    // Anything that overflows on the way is too far away as well
+    let datetime = match unit {
+        "m" | "min" | "mins" | "minute" | "minutes" => Duration::try_minutes(amount.into())
+            .and_then(|duration| now.checked_add_signed(duration)),
+        "h" | "hr" | "hrs" | "hour" | "hours" => {
+            Duration::try_hours(amount.into()).and_then(|duration| now.checked_add_signed(duration))
+        }
+        "d" | "day" | "days" => now.checked_add_days(Days::new(amount.into())),
+        "w" | "wk" | "wks" | "week" | "weeks" => u64::from(amount)
+            .checked_mul(7)
+            .and_then(|days| now.checked_add_days(Days::new(days))),
+        "mo" | "month" | "months" => now.checked_add_months(Months::new(amount)),
+        "y" | "yr" | "year" | "years" => amount
+            .checked_mul(12)
+            .and_then(|months| now.checked_add_months(Months::new(months))),
+        _ => return Ok(None),
+    };
+
+    datetime
+        .map(Some)
+        .ok_or_else(|| format!("\"{}\" is too far away", input))
+}
+
+/// The day `input` names when it's a day without a time, like `2024-06-01`, `tomorrow` or `fri`
+pub fn parse_whole_day(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    parse_day(&input.trim().to_lowercase(), today)
+}
+
+fn parse_day(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    match input {
+        "today" => return Some(today),
+        "tomorrow" | "tmr" | "tom" => return today.succ_opt(),
+        "yesterday" => return today.pred_opt(),
+        _ => {}
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        return Some(date);
+    }
+
+    let (next_week, weekday) = match input.strip_prefix("next ") {
+        Some(weekday) => (true, weekday),
+        None => (false, input),
+    };
+    let weekday = parse_weekday(weekday)?;
+
+    if next_week {
+        // The given day of the week after this one, with weeks starting on Monday
+        let next_monday = today + Days::new(7 - today.weekday().num_days_from_monday() as u64);
+        Some(next_monday + Days::new(weekday.num_days_from_monday() as u64))
+    } else {
+        // The soonest such day after today
+        let days_ahead =
+            (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+        Some(
+            today
+                + Days::new(if days_ahead == 0 {
+                    7
+                } else {
+                    days_ahead as u64
+                }),
+        )
+    }
+}
+
+fn parse_weekday(input: &str) -> Option<Weekday> {
+    let weekday = match input {
+        "mon" | "monday" => Weekday::Mon,
+        "tue" | "tues" | "tuesday" => Weekday::Tue,
+        "wed" | "wednesday" => Weekday::Wed,
+        "thu" | "thur" | "thurs" | "thursday" => Weekday::Thu,
+        "fri" | "friday" => Weekday::Fri,
+        "sat" | "saturday" => Weekday::Sat,
+        "sun" | "sunday" => Weekday::Sun,
+        _ => return None,
+    };
+
+    Some(weekday)
+}
+
+/// `14:30`, `14:30:15`, `9am` or `5:30pm`
+fn parse_time(input: &str) -> Option<NaiveTime> {
+    for format in ["%H:%M", "%H:%M:%S"] {
+        if let Ok(time) = NaiveTime::parse_from_str(input, format) {
+            return Some(time);
+        }
+    }
+
+    let (clock, is_pm) = match (input.strip_suffix("am"), input.strip_suffix("pm")) {
+        (Some(clock), _) => (clock, false),
+        (_, Some(clock)) => (clock, true),
+        _ => return None,
+    };
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) => (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?),
+        None => (clock.parse::<u32>().ok()?, 0),
+    };
+    if !(1..=12).contains(&hour) {
+        return None;
+    }
+
+    NaiveTime::from_hms_opt(hour % 12 + if is_pm { 12 } else { 0 }, minute, 0)
+}
+
+/// Interpret a wall-clock time in the local timezone
+pub fn local(datetime: NaiveDateTime) -> Result<DateTime<Local>, String> {
+    Local
+        .from_local_datetime(&datetime)
+        .earliest()
+        .ok_or_else(|| format!("{} doesn't exist in the local timezone", datetime))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A local time that exists, away from any daylight saving change
+    fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    fn due(input: &str, now: DateTime<Local>) -> DateTime<Local> {
+        match parse_due(input, now) {
+            Ok(DueInput::At(due)) => due,
+            other => panic!("{:?} parsed as {:?}", input, other),
+        }
+    }
+
+    // Wednesday 5 June 2024
+    fn wednesday() -> DateTime<Local> {
+        at(2024, 6, 5, 12, 0)
+    }
+
+    #[test]
+    fn iso_dates_and_times() {
+        assert_eq!(due("2024-06-01", wednesday()), at(2024, 6, 1, 0, 0));
+        assert_eq!(due("2024-06-01 14:30", wednesday()), at(2024, 6, 1, 14, 30));
+        assert_eq!(due("2024-06-01T14:30", wednesday()), at(2024, 6, 1, 14, 30));
+    }
+
+    #[test]
+    fn day_with_a_time() {
+        assert_eq!(due("tomorrow 9:00", wednesday()), at(2024, 6, 6, 9, 0));
+        assert_eq!(due("Tomorrow 5:30pm", wednesday()), at(2024, 6, 6, 17, 30));
+    }
+
+    #[test]
+    fn weekdays() {
+        assert_eq!(due("fri", wednesday()), at(2024, 6, 7, 0, 0));
+        assert_eq!(due("next fri", wednesday()), at(2024, 6, 14, 0, 0));
+
+        // On a Friday, `fri` is a week away rather than today, the same as `next fri`
+        let friday = at(2024, 6, 7, 12, 0);
+        assert_eq!(due("fri", friday), at(2024, 6, 14, 0, 0));
+        assert_eq!(due("next fri", friday), at(2024, 6, 14, 0, 0));
+    }
+
+    #[test]
+    fn end_of_week_on_a_sunday_is_that_night() {
+        let sunday = at(2024, 6, 9, 12, 0);
+        assert_eq!(
+            due("eow", sunday),
+            local(
+                NaiveDate::from_ymd_opt(2024, 6, 9)
+                    .unwrap()
+                    .and_hms_opt(23, 59, 59)
+                    .unwrap()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn end_of_month_in_december() {
+        let december = at(2024, 12, 10, 12, 0);
+        assert_eq!(
+            due("eom", december),
+            local(
+                NaiveDate::from_ymd_opt(2024, 12, 31)
+                    .unwrap()
+                    .and_hms_opt(23, 59, 59)
+                    .unwrap()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(due("in 3d", wednesday()), at(2024, 6, 8, 12, 0));
+        assert_eq!(due("2w", wednesday()), at(2024, 6, 19, 12, 0));
+        assert_eq!(due("in 2 hours", wednesday()), at(2024, 6, 5, 14, 0));
+    }
+
+    #[test]
+    fn clearing() {
+        for input in ["", "  ", "none", "NONE", "clear", "-"] {
+            assert_eq!(
+                parse_due(input, wednesday()),
+                Ok(DueInput::Clear),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn offsets_that_overflow_are_too_far_away() {
+        for input in ["in 400000000y", "in 4000000000h", "in 4000000000w"] {
+            let err = parse_due(input, wednesday()).unwrap_err();
+            assert!(err.contains("too far away"), "{:?} gave {:?}", input, err);
+        }
+    }
+
+    #[test]
+    fn unknown_dates() {
+        assert!(parse_due("someday", wednesday()).is_err());
+    }
+}
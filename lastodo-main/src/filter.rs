@@ -0,0 +1,479 @@
+//! Filters that hide the tasks on the board that don't match a query. A query is a list of
+//! terms, and a task has to match all of them:
+//!
+//! - `login` or `"log in"`: the title or description contains the text, ignoring case
+//! - `title:login`: only the title contains it
+//! - `status:doing`: the task is in that column
+//! - `due:<7d`, `due:>=2024-06-01`, `due:fri`: compared with the due date, where the date is
+//!   anything the due field takes. Without `<`, `<=`, `>` or `>=` it means that day, and a
+//!   date without a time stands for the whole day, so `due:<=fri` includes Friday evening.
+//!   `due:none`, `due:any` and `due:overdue` are about whether and when it has one.
+//! - `tag:backend`: the task has a project, context or tag called `backend`. `+backend` and
+//!   `@phone` only look at projects and contexts.
+//! - `pri:a`: the task has that priority, or no priority with `pri:none`
+//!
+//! A `!` at the start of a value, or a `-` at the start of a term, matches the tasks the term
+//! wouldn't: `status:!done`, `-"login"`.
+
+use chrono::{DateTime, Local, NaiveDate, NaiveTime};
+
+use crate::app::{App, CurrentTask, KanbanStatus, Priority, Task};
+use crate::columns::Columns;
+use crate::dates::{self, DueInput};
+
+/// The fields a term can be about
+const FIELDS: &str = "status, due, tag, pri or title";
+
+/// A parsed query
+#[derive(Clone, Debug)]
+pub struct Filter {
+    query: String,
+    terms: Vec<Term>,
+}
+
+#[derive(Clone, Debug)]
+struct Term {
+    condition: Condition,
+    negated: bool,
+}
+
+#[derive(Clone, Debug)]
+enum Condition {
+    /// Lowercase text in the title or description
+    Text(String),
+    /// Lowercase text in the title
+    Title(String),
+    Status(KanbanStatus),
+    Due(Due),
+    /// A project, context or tag
+    Tag(String),
+    Project(String),
+    Context(String),
+    Priority(Option<Priority>),
+}
+
+#[derive(Clone, Debug)]
+enum Due {
+    None,
+    Any,
+    Before(DateTime<Local>),
+    AtOrBefore(DateTime<Local>),
+    After(DateTime<Local>),
+    AtOrAfter(DateTime<Local>),
+    On(NaiveDate),
+}
+
+impl Filter {
+    /// Parse `query`, with column names from `columns` and dates relative to `now`. An empty
+    /// query matches every task.
+    pub fn parse(query: &str, columns: &Columns, now: DateTime<Local>) -> Result<Filter, String> {
+        let terms = split_terms(query)?
+            .iter()
+            .map(|term| {
+                parse_term(term, columns, now).map_err(|err| format!("`{}`: {}", term, err))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Filter {
+            query: query.trim().to_string(),
+            terms,
+        })
+    }
+
+    /// The query the filter was parsed from
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn matches(&self, task: &Task) -> bool {
+        self.terms
+            .iter()
+            .all(|term| term.condition.matches(task) != term.negated)
+    }
+}
+
+impl Condition {
+    fn matches(&self, task: &Task) -> bool {
+        let has = |names: &[String], name: &str| names.iter().any(|n| n.eq_ignore_ascii_case(name));
+
+        match self {
+            Condition::Text(text) => {
+                task.title.to_lowercase().contains(text)
+                    || task.description.to_lowercase().contains(text)
+            }
+            Condition::Title(text) => task.title.to_lowercase().contains(text),
+            Condition::Status(status) => task.kanban_status == *status,
+            Condition::Due(due) => match (due, task.due) {
+                (Due::None, due) => due.is_none(),
+                (Due::Any, due) => due.is_some(),
+                (_, None) => false,
+                (Due::Before(at), Some(due)) => due < *at,
+                (Due::AtOrBefore(at), Some(due)) => due <= *at,
+                (Due::After(at), Some(due)) => due > *at,
+                (Due::AtOrAfter(at), Some(due)) => due >= *at,
+                (Due::On(day), Some(due)) => due.date_naive() == *day,
+            },
+            Condition::Tag(tag) => {
+                has(&task.projects, tag)
+                    || has(&task.contexts, tag)
+                    || task
+                        .tags
+                        .iter()
+                        .any(|(key, _)| key.eq_ignore_ascii_case(tag))
+            }
+            Condition::Project(project) => has(&task.projects, project),
+            Condition::Context(context) => has(&task.contexts, context),
+            Condition::Priority(priority) => task.priority == *priority,
+        }
+    }
+}
+
+/// Split a query at spaces that aren't inside double quotes
+fn split_terms(query: &str) -> Result<Vec<String>, String> {
+    let mut terms = Vec::new();
+    let mut term = String::new();
+    let mut in_quotes = false;
+
+    for c in query.chars() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                term.push(c);
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if !term.is_empty() {
+                    terms.push(std::mem::take(&mut term));
+                }
+            }
+            c => term.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(format!("`{}` is missing its closing quote", term));
+    }
+    if !term.is_empty() {
+        terms.push(term);
+    }
+
+    Ok(terms)
+}
+
+fn parse_term(term: &str, columns: &Columns, now: DateTime<Local>) -> Result<Term, String> {
+    let (negated, term) = match term.strip_prefix('-') {
+        Some(rest) if !rest.is_empty() => (true, rest),
+        _ => (false, term),
+    };
+
+    if let Some(project) = term.strip_prefix('+').filter(|project| !project.is_empty()) {
+        return Ok(Term {
+            condition: Condition::Project(unquote(project)),
+            negated,
+        });
+    }
+    if let Some(context) = term.strip_prefix('@').filter(|context| !context.is_empty()) {
+        return Ok(Term {
+            condition: Condition::Context(unquote(context)),
+            negated,
+        });
+    }
+
+    let Some((field, value)) = term.split_once(':').filter(|_| !term.starts_with('"')) else {
+        return Ok(Term {
+            condition: Condition::Text(unquote(term).to_lowercase()),
+            negated,
+        });
+    };
+
+    // `status:!done` is the same as `-status:done`
+    let (negated, value) = match value.strip_prefix('!') {
+        Some(rest) => (!negated, rest),
+        None => (negated, value),
+    };
+    let value = unquote(value);
+    if value.is_empty() {
+        return Err(String::from("there is nothing after the colon"));
+    }
+
+    let condition = match field.to_lowercase().as_str() {
+        "title" => Condition::Title(value.to_lowercase()),
+        "status" => Condition::Status(columns.find(&value)?),
+        "due" => Condition::Due(parse_due(&value, now)?),
+        "tag" => Condition::Tag(value),
+        "pri" => Condition::Priority(parse_priority(&value)?),
+        _ => {
+            return Err(format!(
+                "unknown field `{}`, expected {}. Put text with a colon in quotes.",
+                field, FIELDS
+            ))
+        }
+    };
+
+    Ok(Term { condition, negated })
+}
+
+fn parse_due(value: &str, now: DateTime<Local>) -> Result<Due, String> {
+    match value.to_lowercase().as_str() {
+        "none" => return Ok(Due::None),
+        "any" => return Ok(Due::Any),
+        "overdue" => return Ok(Due::Before(now)),
+        _ => {}
+    }
+
+    let at = |date: &str| match dates::parse_due(date, now)? {
+        DueInput::At(at) => Ok(at),
+        DueInput::Clear => Err(format!("`{}` is not a date", date)),
+    };
+
+    // A day without a time takes in all of it, so `<=` and `>` go from the start of the next day
+    let next_day = |date: &str| match dates::parse_whole_day(date, now.date_naive()) {
+        Some(day) => day
+            .succ_opt()
+            .ok_or_else(|| format!("`{}` is too far away", date))
+            .and_then(|next| dates::local(next.and_time(NaiveTime::MIN)))
+            .map(Some),
+        None => Ok(None),
+    };
+
+    if let Some(date) = value.strip_prefix("<=") {
+        match next_day(date)? {
+            Some(next) => Ok(Due::Before(next)),
+            None => Ok(Due::AtOrBefore(at(date)?)),
+        }
+    } else if let Some(date) = value.strip_prefix('<') {
+        Ok(Due::Before(at(date)?))
+    } else if let Some(date) = value.strip_prefix(">=") {
+        Ok(Due::AtOrAfter(at(date)?))
+    } else if let Some(date) = value.strip_prefix('>') {
+        match next_day(date)? {
+            Some(next) => Ok(Due::AtOrAfter(next)),
+            None => Ok(Due::After(at(date)?)),
+        }
+    } else {
+        Ok(Due::On(at(value)?.date_naive()))
+    }
+}
+
+fn parse_priority(value: &str) -> Result<Option<Priority>, String> {
+    if value.eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Priority::try_from(c.to_ascii_uppercase()).map(Some),
+        _ => Err(format!(
+            "`{}` is not a priority, expected A to Z or none",
+            value
+        )),
+    }
+}
+
+/// Take the quotes off a quoted value
+fn unquote(value: &str) -> String {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value)
+        .to_string()
+}
+
+impl App {
+    /// The positions of the tasks in `status` that the filter lets through, in the order the
+    /// column shows them
+    pub fn visible_tasks(&self, status: &KanbanStatus) -> Vec<usize> {
+        let Some(tasks) = self.task_list.get(status) else {
+            return Vec::new();
+        };
+
+        self.sorted_tasks(status)
+            .into_iter()
+            .filter(|&index| {
+                self.filter
+                    .as_ref()
+                    .is_none_or(|filter| filter.matches(&tasks[index]))
+            })
+            .collect()
+    }
+
+    /// Focus the `status` column, keeping the cursor on the same row as far as the column goes.
+    /// Empty columns can be focused too, with no task highlighted.
+    pub fn focus_column(&mut self, status: KanbanStatus) {
+        let row = self.cur_task.as_ref().map_or(0, |cur_task| {
+            self.visible_tasks(&cur_task.status)
+                .iter()
+                .position(|&index| index as i16 == cur_task.index)
+                .unwrap_or(0)
+        });
+        let visible = self.visible_tasks(&status);
+        let index = visible
+            .get(row)
+            .or(visible.last())
+            .map_or(-1, |&index| index as i16);
+
+        self.cur_task = Some(CurrentTask { status, index });
+    }
+
+    /// Move the cursor off a task the filter hides, to the next one down that it shows
+    pub fn keep_cursor_visible(&mut self) {
+        let Some(cur_task) = &self.cur_task else {
+            return;
+        };
+        let visible = self.visible_tasks(&cur_task.status);
+        if visible.iter().any(|&index| index as i16 == cur_task.index) {
+            return;
+        }
+
+        // The hidden task and whatever is below it, in the order the column shows them
+        let sorted = self.sorted_tasks(&cur_task.status);
+        let row = sorted
+            .iter()
+            .position(|&index| index as i16 == cur_task.index)
+            .unwrap_or(0);
+        let index = sorted[row..]
+            .iter()
+            .find(|index| visible.contains(index))
+            .or(visible.last())
+            .map_or(-1, |&index| index as i16);
+        self.cur_task = Some(CurrentTask {
+            status: cur_task.status.clone(),
+            index,
+        });
+    }
+
+    /// Show only the tasks matching `query`, or every task if it's empty
+    pub fn apply_filter(&mut self, query: &str) -> Result<(), String> {
+        if query.trim().is_empty() {
+            self.filter = None;
+            self.message = String::from("Showing every task.");
+            return Ok(());
+        }
+
+        let filter = Filter::parse(query, &self.columns, Local::now())?;
+        self.filter = Some(filter);
+        self.keep_cursor_visible();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, TimeZone};
+    use lastodo_config::ColumnList;
+
+    use super::*;
+
+    fn now() -> DateTime<Local> {
+        Local.with_ymd_and_hms(2024, 6, 5, 12, 0, 0).unwrap()
+    }
+
+    fn parse(query: &str) -> Result<Filter, String> {
+        Filter::parse(query, &Columns::new(&ColumnList::default()), now())
+    }
+
+    fn matches(query: &str, task: &Task) -> bool {
+        parse(query).unwrap().matches(task)
+    }
+
+    fn task(title: &str) -> Task {
+        Task {
+            title: title.to_string(),
+            kanban_status: KanbanStatus::new("Todo"),
+            ..Task::default()
+        }
+    }
+
+    #[test]
+    fn terms_split_at_spaces_outside_quotes() {
+        assert_eq!(
+            split_terms(r#"login  "log in" title:"a b""#).unwrap(),
+            vec!["login", r#""log in""#, r#"title:"a b""#]
+        );
+        assert!(parse(r#""log in""#)
+            .unwrap()
+            .matches(&task("Fix the log in page")));
+    }
+
+    #[test]
+    fn unclosed_quotes_are_an_error() {
+        let err = parse(r#"fix "log in"#).unwrap_err();
+        assert!(err.contains("missing its closing quote"), "{}", err);
+    }
+
+    #[test]
+    fn negation() {
+        let login = task("Fix login");
+        let done = Task {
+            kanban_status: KanbanStatus::new("Done"),
+            ..task("Ship it")
+        };
+
+        assert!(!matches("-login", &login));
+        assert!(matches("-login", &done));
+        assert!(matches("status:!done", &login));
+        assert!(!matches("status:!done", &done));
+        // Both ways of negating cancel out
+        assert!(matches("-status:!done", &done));
+        assert!(!matches("-status:!done", &login));
+    }
+
+    #[test]
+    fn due_dates() {
+        let due_in = |days| Task {
+            due: Some(now() + Duration::days(days)),
+            ..task("Due")
+        };
+        let no_due = task("Whenever");
+
+        assert!(matches("due:<7d", &due_in(3)));
+        assert!(!matches("due:<7d", &due_in(10)));
+        assert!(!matches("due:<7d", &no_due));
+
+        assert!(matches("due:overdue", &due_in(-1)));
+        assert!(!matches("due:overdue", &due_in(1)));
+        assert!(!matches("due:overdue", &no_due));
+
+        assert!(matches("due:none", &no_due));
+        assert!(!matches("due:none", &due_in(1)));
+
+        // A date on its own is the whole day, whichever way it's compared
+        let afternoon = Task {
+            due: Some(Local.with_ymd_and_hms(2024, 6, 1, 14, 30, 0).unwrap()),
+            ..task("Afternoon")
+        };
+        assert!(matches("due:2024-06-01", &afternoon));
+        assert!(matches("due:<=2024-06-01", &afternoon));
+        assert!(!matches("due:<2024-06-01", &afternoon));
+        assert!(matches("due:>=2024-06-01", &afternoon));
+        assert!(!matches("due:>2024-06-01", &afternoon));
+        assert!(matches("due:>2024-05-31", &afternoon));
+
+        // With a time it's that moment
+        assert!(!matches("due:<=2024-06-01T14:00", &afternoon));
+        assert!(matches("due:>2024-06-01T14:00", &afternoon));
+    }
+
+    #[test]
+    fn unknown_columns_list_the_board_columns() {
+        let err = parse("status:nope").unwrap_err();
+        assert_eq!(
+            err,
+            "`status:nope`: unknown column `nope`, expected one of Todo, Partial, Doing, Done, \
+             Blocked"
+        );
+    }
+
+    #[test]
+    fn priorities() {
+        let urgent = Task {
+            priority: Some(Priority::try_from('A').unwrap()),
+            ..task("Urgent")
+        };
+        let unprioritised = task("Someday");
+
+        assert!(matches("pri:none", &unprioritised));
+        assert!(!matches("pri:none", &urgent));
+        assert!(matches("pri:a", &urgent));
+        assert!(!matches("pri:a", &unprioritised));
+    }
+}
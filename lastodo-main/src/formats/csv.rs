@@ -0,0 +1,112 @@
+//! The board as CSV, one task per row, for spreadsheets. Archived tasks are included, with
+//! the time they were archived in the last column.
+
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+
+use crate::app::{ArchivedTask, KanbanStatus, Priority, Task, TaskId};
+use crate::store::Board;
+
+#[derive(Serialize, Deserialize)]
+struct Row {
+    id: TaskId,
+    title: String,
+    status: KanbanStatus,
+    description: String,
+    due: Option<DateTime<Local>>,
+    moved_at: Option<DateTime<Local>>,
+    created_at: Option<DateTime<Local>>,
+    updated_at: Option<DateTime<Local>>,
+    priority: Option<Priority>,
+    // Lists are joined with spaces, and tags are written as `key:value`
+    projects: String,
+    contexts: String,
+    tags: String,
+    archived_at: Option<DateTime<Local>>,
+}
+
+impl Row {
+    fn new(task: &Task, archived_at: Option<DateTime<Local>>) -> Row {
+        Row {
+            id: task.id,
+            title: task.title.clone(),
+            status: task.kanban_status.clone(),
+            description: task.description.clone(),
+            due: task.due,
+            moved_at: task.moved_at,
+            created_at: task.created_at,
+            updated_at: task.updated_at,
+            priority: task.priority,
+            projects: task.projects.join(" "),
+            contexts: task.contexts.join(" "),
+            tags: task
+                .tags
+                .iter()
+                .map(|(key, value)| format!("{}:{}", key, value))
+                .collect::<Vec<_>>()
+                .join(" "),
+            archived_at,
+        }
+    }
+}
+
+pub fn export(board: &Board) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+
+    let rows = board
+        .task_list
+        .values()
+        .flatten()
+        .map(|task| Row::new(task, None))
+        .chain(
+            board
+                .archive
+                .iter()
+                .map(|archived| Row::new(&archived.task, Some(archived.archived_at))),
+        );
+    for row in rows {
+        writer.serialize(row).map_err(|err| err.to_string())?;
+    }
+
+    let bytes = writer.into_inner().map_err(|err| err.to_string())?;
+    String::from_utf8(bytes).map_err(|err| err.to_string())
+}
+
+pub fn import(input: &str) -> Result<Board, String> {
+    let mut board = Board::default();
+
+    let mut reader = csv::Reader::from_reader(input.as_bytes());
+    for row in reader.deserialize() {
+        let row: Row = row.map_err(|err| err.to_string())?;
+        let task = Task {
+            id: row.id,
+            title: row.title,
+            kanban_status: row.status,
+            description: row.description,
+            due: row.due,
+            moved_at: row.moved_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            priority: row.priority,
+            projects: row.projects.split_whitespace().map(String::from).collect(),
+            contexts: row.contexts.split_whitespace().map(String::from).collect(),
+            tags: row
+                .tags
+                .split_whitespace()
+                .filter_map(|tag| tag.split_once(':'))
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+        };
+
+        match row.archived_at {
+            Some(archived_at) => board.archive.push(ArchivedTask { task, archived_at }),
+            None => board
+                .task_list
+                .entry(task.kanban_status.clone())
+                .or_default()
+                .push(task),
+        }
+    }
+
+    Ok(board)
+}
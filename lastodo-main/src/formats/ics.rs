@@ -0,0 +1,117 @@
+//! Tasks with a due date as iCalendar `VTODO`s (RFC 5545), for calendar apps. This only goes
+//! one way: calendars are for looking at deadlines, and the board stays the place to edit them.
+//!
+//! Each task's UID is made from its ID, so a calendar that subscribes to the file again
+//! updates the entries it has instead of adding new ones.
+
+use chrono::{DateTime, Local, NaiveTime, Utc};
+
+use crate::app::KanbanStatus;
+use crate::columns::Columns;
+use crate::store::Board;
+
+/// Lines longer than this many bytes are folded onto the next line
+const MAX_LINE_LENGTH: usize = 75;
+
+pub fn export(board: &Board, columns: &Columns) -> Result<String, String> {
+    let now = Utc::now();
+    let mut lines = vec![
+        String::from("BEGIN:VCALENDAR"),
+        String::from("VERSION:2.0"),
+        String::from("PRODID:-//lastodo//lastodo//EN"),
+    ];
+
+    for task in board.task_list.values().flatten() {
+        let Some(due) = task.due else {
+            continue;
+        };
+
+        lines.push(String::from("BEGIN:VTODO"));
+        lines.push(format!("UID:{}@lastodo", task.id));
+        lines.push(format!("DTSTAMP:{}", timestamp(now)));
+        if let Some(created_at) = task.created_at {
+            lines.push(format!(
+                "CREATED:{}",
+                timestamp(created_at.with_timezone(&Utc))
+            ));
+        }
+        lines.push(format!("SUMMARY:{}", escape(&task.title)));
+        if !task.description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape(&task.description)));
+        }
+        lines.push(format_due(due));
+        lines.push(format!("STATUS:{}", status(&task.kanban_status, columns)));
+        lines.push(format!(
+            "CATEGORIES:{}",
+            escape(&task.kanban_status.to_string())
+        ));
+        if let Some(priority) = task.priority {
+            // iCalendar priorities go from 1 (highest) to 9 (lowest)
+            let priority = (char::from(priority) as u8 - b'A' + 1).min(9);
+            lines.push(format!("PRIORITY:{}", priority));
+        }
+        if let (true, Some(moved_at)) = (columns.is_done(&task.kanban_status), task.moved_at) {
+            lines.push(format!(
+                "COMPLETED:{}",
+                timestamp(moved_at.with_timezone(&Utc))
+            ));
+        }
+        lines.push(String::from("END:VTODO"));
+    }
+
+    lines.push(String::from("END:VCALENDAR"));
+
+    Ok(lines.iter().map(|line| fold(line)).collect())
+}
+
+/// Tasks due at midnight are due on that day, with no time
+fn format_due(due: DateTime<Local>) -> String {
+    if due.time() == NaiveTime::MIN {
+        format!("DUE;VALUE=DATE:{}", due.format("%Y%m%d"))
+    } else {
+        format!("DUE:{}", timestamp(due.with_timezone(&Utc)))
+    }
+}
+
+fn timestamp(datetime: DateTime<Utc>) -> String {
+    datetime.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Tasks in the first column haven't been started, and ones in the columns after it are
+/// under way until they're done
+fn status(status: &KanbanStatus, columns: &Columns) -> &'static str {
+    if columns.is_done(status) {
+        "COMPLETED"
+    } else if status == columns.first() {
+        "NEEDS-ACTION"
+    } else {
+        "IN-PROCESS"
+    }
+}
+
+/// Escape the characters that mean something in a text value
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// End a line with CRLF, folding it onto continuation lines that start with a space if it's
+/// too long. Lines are only split between characters.
+fn fold(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > MAX_LINE_LENGTH {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+
+    folded
+}
@@ -0,0 +1,11 @@
+//! The board as JSON, exactly as it is stored
+
+use crate::store::Board;
+
+pub fn export(board: &Board) -> Result<String, String> {
+    serde_json::to_string_pretty(board).map_err(|err| err.to_string())
+}
+
+pub fn import(input: &str) -> Result<Board, String> {
+    serde_json::from_str(input).map_err(|err| err.to_string())
+}
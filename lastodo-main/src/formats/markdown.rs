@@ -0,0 +1,276 @@
+//! The board as a Markdown checklist, laid out the way the Obsidian Kanban plugin keeps its
+//! boards. Each column is a `##` heading followed by its tasks:
+//!
+//! ```markdown
+//! ## Todo
+//!
+//! - [ ] Write the report @{2024-06-01} @@{14:30} <!-- id:01HZX... -->
+//!     The description, indented under the task with a tab
+//! ```
+//!
+//! Tasks in the done column are checked, and checked tasks go into the done column when
+//! they're imported, whichever lane they're under, as ticking a card completes it. Due dates
+//! use the plugin's `@{date}` and `@@{time}` notation, and the ID is kept in an HTML comment,
+//! which neither Obsidian nor other renderers show.
+
+use chrono::{NaiveDate, NaiveTime};
+
+use crate::app::{KanbanStatus, Task};
+use crate::columns::Columns;
+use crate::dates;
+use crate::store::Board;
+
+/// The front matter that tells Obsidian the file is a board
+const FRONT_MATTER: &str = "---\n\nkanban-plugin: basic\n\n---\n";
+
+pub fn export(board: &Board, columns: &Columns) -> Result<String, String> {
+    let mut output = String::from(FRONT_MATTER);
+
+    for (status, tasks) in &board.task_list {
+        output.push_str(&format!("\n## {}\n\n", status));
+        for task in tasks {
+            output.push_str(&format_task(task, columns.is_done(status)));
+        }
+    }
+
+    Ok(output)
+}
+
+/// Headings are matched to the board's columns ignoring case. Headings that don't match any
+/// become new columns, as Obsidian boards can have any lanes.
+pub fn import(input: &str, columns: &Columns) -> Result<Board, String> {
+    let mut board = Board::default();
+    let mut column: Option<KanbanStatus> = None;
+    let mut in_front_matter = false;
+    let mut in_comment = false;
+
+    for (number, line) in input.lines().enumerate() {
+        let error = |err: String| format!("line {}: {}", number + 1, err);
+
+        // Front matter and the plugin's settings block aren't part of the board
+        if number == 0 && line.trim() == "---" {
+            in_front_matter = true;
+            continue;
+        }
+        if in_front_matter {
+            in_front_matter = line.trim() != "---";
+            continue;
+        }
+        if let Some(comment) = line.trim().strip_prefix("%%") {
+            in_comment = !in_comment && !comment.ends_with("%%");
+            continue;
+        }
+        if in_comment {
+            continue;
+        }
+
+        if let Some(heading) = line.strip_prefix("## ") {
+            let heading = heading.trim();
+            column = Some(
+                columns
+                    .find(heading)
+                    .unwrap_or_else(|_| KanbanStatus::new(heading)),
+            );
+            continue;
+        }
+
+        if let Some((checked, item)) = parse_item(line) {
+            let mut status = column
+                .clone()
+                .ok_or_else(|| error(String::from("the task isn't under a column heading")))?;
+            if checked && !columns.is_done(&status) {
+                status = columns.done().cloned().ok_or_else(|| {
+                    error(String::from(
+                        "the task is checked, but the board has no done column",
+                    ))
+                })?;
+            }
+            let task = parse_task(item, status.clone()).map_err(error)?;
+            board.task_list.entry(status).or_default().push(task);
+        } else if let Some(text) = line
+            .strip_prefix('\t')
+            .or_else(|| line.strip_prefix("    "))
+        {
+            // Indented lines belong to the task above them
+            let task = column
+                .as_ref()
+                .and_then(|status| board.task_list.get_mut(status))
+                .and_then(|tasks| tasks.last_mut())
+                .ok_or_else(|| error(String::from("indented text isn't under a task")))?;
+            if !task.description.is_empty() {
+                task.description.push('\n');
+            }
+            task.description.push_str(text);
+        }
+    }
+
+    Ok(board)
+}
+
+/// Keep what the Markdown board can't hold from our version of a task, so importing a file
+/// that was exported from this board doesn't look like a change
+pub fn reconcile(ours: &Task, theirs: &mut Task) {
+    theirs.moved_at = ours.moved_at;
+    theirs.created_at = ours.created_at;
+    theirs.updated_at = ours.updated_at;
+    theirs.priority = ours.priority;
+    theirs.projects = ours.projects.clone();
+    theirs.contexts = ours.contexts.clone();
+    theirs.tags = ours.tags.clone();
+}
+
+fn format_task(task: &Task, done: bool) -> String {
+    let mut line = format!("- [{}] {}", if done { "x" } else { " " }, task.title);
+    if let Some(due) = task.due {
+        line.push_str(&format!(" @{{{}}}", due.format("%Y-%m-%d")));
+        if due.time() != NaiveTime::MIN {
+            line.push_str(&format!(" @@{{{}}}", due.format("%H:%M")));
+        }
+    }
+    line.push_str(&format!(" <!-- id:{} -->\n", task.id));
+
+    for description_line in task.description.lines() {
+        line.push_str(&format!("\t{}\n", description_line));
+    }
+
+    line
+}
+
+/// Whether a `- [ ]` or `- [x]` line is checked, and its text
+fn parse_item(line: &str) -> Option<(bool, &str)> {
+    let rest = line.strip_prefix("- [")?;
+    let mut chars = rest.chars();
+    match (chars.next(), chars.next()) {
+        (Some(' '), Some(']')) => Some((false, rest[2..].trim())),
+        (Some('x' | 'X'), Some(']')) => Some((true, rest[2..].trim())),
+        _ => None,
+    }
+}
+
+fn parse_task(item: &str, status: KanbanStatus) -> Result<Task, String> {
+    let mut task = Task {
+        kanban_status: status,
+        created_at: None,
+        ..Task::default()
+    };
+
+    let mut text = item.to_string();
+    if let Some((before, id, after)) = extract(&text, "<!-- id:", "-->") {
+        task.id = id
+            .trim()
+            .parse()
+            .map_err(|_| format!("`{}` is not a task ID", id.trim()))?;
+        text = format!("{}{}", before, after);
+    }
+
+    // `@@{…}` first, since `@{…}` would match it too
+    let mut time = None;
+    if let Some((before, value, after)) = extract(&text, "@@{", "}") {
+        time = Some(
+            NaiveTime::parse_from_str(value, "%H:%M")
+                .map_err(|_| format!("`{}` is not a time", value))?,
+        );
+        text = format!("{}{}", before, after);
+    }
+    if let Some((before, value, after)) = extract(&text, "@{", "}") {
+        let date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .map_err(|_| format!("`{}` is not a date", value))?;
+        task.due = Some(dates::local(date.and_time(time.unwrap_or(NaiveTime::MIN)))?);
+        text = format!("{}{}", before, after);
+    }
+
+    task.title = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if task.title.is_empty() {
+        return Err(String::from("the task has no text"));
+    }
+
+    Ok(task)
+}
+
+/// Split `text` around the first `start…end`, giving what's before, between and after
+fn extract<'a>(text: &'a str, start: &str, end: &str) -> Option<(&'a str, &'a str, &'a str)> {
+    let from = text.find(start)?;
+    let inner = &text[from + start.len()..];
+    let to = inner.find(end)?;
+
+    Some((&text[..from], &inner[..to], &inner[to + end.len()..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Local, TimeZone};
+    use lastodo_config::ColumnList;
+
+    use super::*;
+
+    fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn export_and_import_round_trip() {
+        let columns = Columns::new(&ColumnList::default());
+        let open = Task {
+            title: String::from("Ship it"),
+            description: String::from("First line\nSecond line"),
+            kanban_status: KanbanStatus::new("Doing"),
+            due: Some(at(2024, 6, 1, 14, 30)),
+            created_at: Some(at(2024, 5, 20, 9, 0)),
+            tags: vec![
+                (String::from("rec"), String::from("1w")),
+                (String::from("rec"), String::from("2w")),
+            ],
+            ..Task::default()
+        };
+        let done = Task {
+            title: String::from("Write the notes"),
+            kanban_status: KanbanStatus::new("Done"),
+            due: Some(at(2024, 5, 31, 0, 0)),
+            moved_at: Some(at(2024, 5, 30, 8, 15)),
+            ..Task::default()
+        };
+
+        let mut board = Board::default();
+        for task in [&open, &done] {
+            board
+                .task_list
+                .entry(task.kanban_status.clone())
+                .or_default()
+                .push(task.clone());
+        }
+
+        let exported = export(&board, &columns).unwrap();
+        let imported = import(&exported, &columns).unwrap();
+
+        for ours in [&open, &done] {
+            let mut theirs = imported.task_list[&ours.kanban_status]
+                .iter()
+                .find(|task| task.id == ours.id)
+                .unwrap_or_else(|| panic!("{} is missing from:\n{}", ours.title, exported))
+                .clone();
+            reconcile(ours, &mut theirs);
+            assert_eq!(&theirs, ours, "exported as:\n{}", exported);
+        }
+    }
+
+    #[test]
+    fn checked_tasks_are_done() {
+        let columns = Columns::new(&ColumnList::default());
+        let board = import(
+            "## Todo\n\n- [x] Already done\n- [ ] Still to do\n",
+            &columns,
+        )
+        .unwrap();
+
+        assert_eq!(
+            board.task_list[&KanbanStatus::new("Done")][0].title,
+            "Already done"
+        );
+        assert_eq!(
+            board.task_list[&KanbanStatus::new("Todo")][0].title,
+            "Still to do"
+        );
+    }
+}
@@ -0,0 +1,269 @@
+//! Moving boards in and out of lastodo, for other machines and other programs.
+//!
+//! Every format turns a [`Board`] into text and back. Importing merges into the board that is
+//! already there, matching tasks by ID, so importing the same file twice changes nothing.
+
+pub mod csv;
+pub mod ics;
+pub mod json;
+pub mod markdown;
+pub mod todotxt;
+
+use std::path::Path;
+
+use chrono::{DateTime, Local};
+use clap::ValueEnum;
+
+use crate::app::{ArchivedTask, Task, TaskId};
+use crate::columns::Columns;
+use crate::store::Board;
+
+/// The formats a board can be exported to and imported from
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum BoardFormat {
+    Json,
+    Csv,
+    #[value(name = "todotxt")]
+    TodoTxt,
+    Markdown,
+    /// Export only
+    Ics,
+}
+
+impl BoardFormat {
+    /// Guess the format of a file from its extension
+    pub fn from_path(path: &Path) -> Option<BoardFormat> {
+        let extension = path.extension()?.to_str()?.to_lowercase();
+        match extension.as_str() {
+            "txt" => Some(BoardFormat::TodoTxt),
+            "md" => Some(BoardFormat::Markdown),
+            _ => BoardFormat::from_str(&extension, true).ok(),
+        }
+    }
+
+    pub fn export(self, board: &Board, columns: &Columns) -> Result<String, String> {
+        match self {
+            BoardFormat::Json => json::export(board),
+            BoardFormat::Csv => csv::export(board),
+            BoardFormat::TodoTxt => todotxt::export(board, columns),
+            BoardFormat::Markdown => markdown::export(board, columns),
+            BoardFormat::Ics => ics::export(board, columns),
+        }
+    }
+
+    /// Read a board. Columns are matched against `columns` in formats that name them loosely.
+    pub fn import(self, input: &str, columns: &Columns) -> Result<Board, String> {
+        match self {
+            BoardFormat::Json => json::import(input),
+            BoardFormat::Csv => csv::import(input),
+            BoardFormat::TodoTxt => todotxt::import(input, columns),
+            BoardFormat::Markdown => markdown::import(input, columns),
+            BoardFormat::Ics => Err(String::from("iCalendar files can only be exported")),
+        }
+    }
+
+    /// Fill in what the format can't hold from our version of an imported task, so it isn't
+    /// counted as a difference
+    pub fn reconcile(self, ours: &Task, theirs: &mut Task, columns: &Columns) {
+        match self {
+            BoardFormat::Json | BoardFormat::Csv | BoardFormat::Ics => {}
+            BoardFormat::TodoTxt => todotxt::reconcile(ours, theirs, columns),
+            BoardFormat::Markdown => markdown::reconcile(ours, theirs),
+        }
+    }
+}
+
+/// A task that is in the imported board and differs from the one with the same ID on this board
+#[derive(Debug)]
+pub struct Conflict {
+    pub id: TaskId,
+    pub title: String,
+    pub fields: Vec<&'static str>,
+}
+
+/// What happened to each task of an import
+#[derive(Debug, Default)]
+pub struct MergeReport {
+    pub added: usize,
+    pub unchanged: usize,
+    pub overwritten: usize,
+    pub conflicts: Vec<Conflict>,
+}
+
+/// A task as it appears in a board, whether on it or in its archive
+struct Entry {
+    task: Task,
+    archived_at: Option<DateTime<Local>>,
+}
+
+impl Board {
+    /// Take a task out of the board or its archive
+    fn take(&mut self, id: TaskId) -> Option<Entry> {
+        for tasks in self.task_list.values_mut() {
+            if let Some(index) = tasks.iter().position(|task| task.id == id) {
+                return Some(Entry {
+                    task: tasks.remove(index),
+                    archived_at: None,
+                });
+            }
+        }
+
+        let index = self
+            .archive
+            .iter()
+            .position(|archived| archived.task.id == id)?;
+        let archived = self.archive.remove(index);
+        Some(Entry {
+            task: archived.task,
+            archived_at: Some(archived.archived_at),
+        })
+    }
+
+    fn get(&self, id: TaskId) -> Option<Entry> {
+        let on_board = self
+            .task_list
+            .values()
+            .flatten()
+            .find(|task| task.id == id)
+            .map(|task| Entry {
+                task: task.clone(),
+                archived_at: None,
+            });
+
+        on_board.or_else(|| {
+            self.archive
+                .iter()
+                .find(|archived| archived.task.id == id)
+                .map(|archived| Entry {
+                    task: archived.task.clone(),
+                    archived_at: Some(archived.archived_at),
+                })
+        })
+    }
+
+    /// Add a task at the bottom of its column, or to the archive
+    fn put(&mut self, entry: Entry) {
+        match entry.archived_at {
+            Some(archived_at) => self.archive.push(ArchivedTask {
+                task: entry.task,
+                archived_at,
+            }),
+            None => self
+                .task_list
+                .entry(entry.task.kanban_status.clone())
+                .or_default()
+                .push(entry.task),
+        }
+    }
+
+    /// Bring the tasks of `imported` into this board. Tasks that are new here are added, and
+    /// tasks that are here already are left alone unless they differ. Those are conflicts, and
+    /// are only replaced by the imported version if `overwrite` is set. `reconcile` fills in
+    /// what the imported format can't hold from our version of a task before they're compared.
+    pub fn merge(
+        &mut self,
+        imported: Board,
+        overwrite: bool,
+        reconcile: impl Fn(&Task, &mut Task),
+    ) -> MergeReport {
+        let mut report = MergeReport::default();
+
+        let entries = imported
+            .task_list
+            .into_values()
+            .flatten()
+            .map(|task| Entry {
+                task,
+                archived_at: None,
+            })
+            .chain(imported.archive.into_iter().map(|archived| Entry {
+                task: archived.task,
+                archived_at: Some(archived.archived_at),
+            }));
+
+        for mut entry in entries {
+            let Some(existing) = self.get(entry.task.id) else {
+                self.put(entry);
+                report.added += 1;
+                continue;
+            };
+
+            reconcile(&existing.task, &mut entry.task);
+            let fields = differences(&existing, &entry);
+            if fields.is_empty() {
+                report.unchanged += 1;
+            } else if overwrite {
+                self.replace(entry);
+                report.overwritten += 1;
+            } else {
+                report.conflicts.push(Conflict {
+                    id: entry.task.id,
+                    title: existing.task.title,
+                    fields,
+                });
+            }
+        }
+
+        report
+    }
+
+    /// Replace the task with the same ID, keeping its place if it stays in the same column
+    fn replace(&mut self, entry: Entry) {
+        if entry.archived_at.is_none() {
+            let tasks = self.task_list.get_mut(&entry.task.kanban_status);
+            if let Some(task) =
+                tasks.and_then(|tasks| tasks.iter_mut().find(|task| task.id == entry.task.id))
+            {
+                *task = entry.task;
+                return;
+            }
+        }
+
+        self.take(entry.task.id);
+        self.put(entry);
+    }
+}
+
+/// The names of the fields that differ between two versions of a task
+fn differences(ours: &Entry, theirs: &Entry) -> Vec<&'static str> {
+    let (ours_task, theirs_task) = (&ours.task, &theirs.task);
+    let mut fields = Vec::new();
+    if ours_task.title != theirs_task.title {
+        fields.push("title");
+    }
+    if ours_task.kanban_status != theirs_task.kanban_status {
+        fields.push("status");
+    }
+    if ours_task.description != theirs_task.description {
+        fields.push("description");
+    }
+    if ours_task.due != theirs_task.due {
+        fields.push("due");
+    }
+    if ours_task.moved_at != theirs_task.moved_at {
+        fields.push("moved_at");
+    }
+    if ours_task.created_at != theirs_task.created_at {
+        fields.push("created_at");
+    }
+    if ours_task.updated_at != theirs_task.updated_at {
+        fields.push("updated_at");
+    }
+    if ours_task.priority != theirs_task.priority {
+        fields.push("priority");
+    }
+    if ours_task.projects != theirs_task.projects {
+        fields.push("projects");
+    }
+    if ours_task.contexts != theirs_task.contexts {
+        fields.push("contexts");
+    }
+    if ours_task.tags != theirs_task.tags {
+        fields.push("tags");
+    }
+    if ours.archived_at.is_some() != theirs.archived_at.is_some() {
+        fields.push("archived");
+    }
+
+    fields
+}
@@ -0,0 +1,283 @@
+//! The board as a todo.txt file, one task per line. See
+//! <https://github.com/todotxt/todo.txt> for the format.
+//!
+//! Completed lines (`x`) are the board's done column and other lines are in its first column,
+//! unless a `status:` tag says otherwise. The task's ID is kept in an `id:` tag, so importing
+//! the file again merges instead of duplicating.
+//! Any other `key:value` tags are kept on the task and written back out as they were.
+//! Descriptions and archived tasks have no place in todo.txt and are left out.
+
+use chrono::{DateTime, Local, NaiveDate, NaiveTime};
+use ulid::Ulid;
+
+use crate::app::{Priority, Task};
+use crate::columns::Columns;
+use crate::dates::{self, DueInput};
+use crate::store::Board;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+pub fn export(board: &Board, columns: &Columns) -> Result<String, String> {
+    let lines: Vec<String> = board
+        .task_list
+        .values()
+        .flatten()
+        .map(|task| format_task(task, columns))
+        .collect();
+    Ok(lines.iter().map(|line| format!("{}\n", line)).collect())
+}
+
+pub fn import(input: &str, columns: &Columns) -> Result<Board, String> {
+    let mut board = Board::default();
+
+    for (number, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let task =
+            parse_task(line, columns).map_err(|err| format!("line {}: {}", number + 1, err))?;
+        board
+            .task_list
+            .entry(task.kanban_status.clone())
+            .or_default()
+            .push(task);
+    }
+
+    Ok(board)
+}
+
+/// Keep what todo.txt can't hold from our version of a task, so importing a file that was
+/// exported from this board doesn't look like a change
+pub fn reconcile(ours: &Task, theirs: &mut Task, columns: &Columns) {
+    theirs.description = ours.description.clone();
+    theirs.updated_at = ours.updated_at;
+
+    // Only dates are written, so the same day means the same time
+    let same_day = |a: Option<DateTime<Local>>, b: Option<DateTime<Local>>| {
+        a.map(|a| a.date_naive()) == b.map(|b| b.date_naive())
+    };
+    if same_day(ours.created_at, theirs.created_at) {
+        theirs.created_at = ours.created_at;
+    }
+    if !columns.is_done(&theirs.kanban_status) || same_day(ours.moved_at, theirs.moved_at) {
+        theirs.moved_at = ours.moved_at;
+    }
+}
+
+fn format_task(task: &Task, columns: &Columns) -> String {
+    let mut words = Vec::new();
+
+    let done = columns.is_done(&task.kanban_status);
+    let date = |datetime: DateTime<Local>| datetime.format(DATE_FORMAT).to_string();
+    if done {
+        // A creation date is only allowed after a completion date
+        words.push(String::from("x"));
+        if let Some(completed) = task.moved_at {
+            words.push(date(completed));
+            words.extend(task.created_at.map(date));
+        }
+    } else {
+        words.extend(task.priority.map(|priority| format!("({})", priority)));
+        words.extend(task.created_at.map(date));
+    }
+
+    words.push(task.title.clone());
+    words.extend(task.projects.iter().map(|project| format!("+{}", project)));
+    words.extend(task.contexts.iter().map(|context| format!("@{}", context)));
+
+    if let Some(due) = task.due {
+        let format = if due.time() == NaiveTime::MIN {
+            DATE_FORMAT
+        } else {
+            "%Y-%m-%dT%H:%M"
+        };
+        words.push(format!("due:{}", due.format(format)));
+    }
+    if !done && task.kanban_status != *columns.first() {
+        words.push(format!("status:{}", task.kanban_status.slug()));
+    }
+    if done {
+        words.extend(task.priority.map(|priority| format!("pri:{}", priority)));
+    }
+    words.extend(
+        task.tags
+            .iter()
+            .map(|(key, value)| format!("{}:{}", key, value)),
+    );
+    words.push(format!("id:{}", task.id));
+
+    words.join(" ")
+}
+
+fn parse_task(line: &str, columns: &Columns) -> Result<Task, String> {
+    let mut words = line.split_whitespace().peekable();
+    let mut task = Task {
+        id: Ulid::new(),
+        kanban_status: columns.first().clone(),
+        created_at: None,
+        ..Task::default()
+    };
+
+    let done = words.next_if_eq(&"x").is_some();
+    if done {
+        task.kanban_status = columns
+            .done()
+            .ok_or("the task is completed, but the board has no done column")?
+            .clone();
+        if let Some(completed) = words.next_if(|word| parse_date(word).is_some()) {
+            task.moved_at = parse_date(completed);
+            task.created_at = words
+                .next_if(|word| parse_date(word).is_some())
+                .and_then(parse_date);
+        }
+    } else {
+        task.priority = words
+            .next_if(|word| parse_priority(word).is_some())
+            .and_then(parse_priority);
+        task.created_at = words
+            .next_if(|word| parse_date(word).is_some())
+            .and_then(parse_date);
+    }
+
+    let mut title = Vec::new();
+    for word in words {
+        if let Some(project) = word.strip_prefix('+').filter(|project| !project.is_empty()) {
+            task.projects.push(project.to_string());
+        } else if let Some(context) = word.strip_prefix('@').filter(|context| !context.is_empty()) {
+            task.contexts.push(context.to_string());
+        } else if let Some((key, value)) = parse_tag(word) {
+            if !apply_tag(&mut task, key, value, done, columns) {
+                task.tags.push((key.to_string(), value.to_string()));
+            }
+        } else {
+            title.push(word);
+        }
+    }
+
+    if title.is_empty() {
+        return Err(String::from("the task has no text"));
+    }
+    task.title = title.join(" ");
+
+    Ok(task)
+}
+
+/// Put the tags lastodo knows about into their fields. Returns false for tags it doesn't know,
+/// or whose value doesn't make sense, which are kept as they are.
+fn apply_tag(task: &mut Task, key: &str, value: &str, done: bool, columns: &Columns) -> bool {
+    match key {
+        "id" => match value.parse() {
+            Ok(id) => task.id = id,
+            Err(_) => return false,
+        },
+        "due" => match dates::parse_due(value, Local::now()) {
+            Ok(DueInput::At(due)) => task.due = Some(due),
+            _ => return false,
+        },
+        // Done comes from the `x`, which wins over any status tag
+        "status" if done => {}
+        "status" => match columns.find(value) {
+            Ok(status) => task.kanban_status = status,
+            Err(_) => return false,
+        },
+        "pri" if done => match value.chars().next().map(Priority::try_from) {
+            Some(Ok(priority)) if value.len() == 1 => task.priority = Some(priority),
+            _ => return false,
+        },
+        _ => return false,
+    }
+
+    true
+}
+
+/// `key:value`, where neither side is empty. The value can have colons of its own, as in
+/// `due:2024-06-01T14:30`. Links like `https://…` aren't tags.
+fn parse_tag(word: &str) -> Option<(&str, &str)> {
+    let (key, value) = word.split_once(':')?;
+    if key.is_empty() || value.is_empty() || value.starts_with("//") {
+        return None;
+    }
+
+    Some((key, value))
+}
+
+/// `(A)` to `(Z)`
+fn parse_priority(word: &str) -> Option<Priority> {
+    let letter = word.strip_prefix('(')?.strip_suffix(')')?;
+    let mut chars = letter.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Priority::try_from(c).ok(),
+        _ => None,
+    }
+}
+
+fn parse_date(word: &str) -> Option<DateTime<Local>> {
+    let date = NaiveDate::parse_from_str(word, DATE_FORMAT).ok()?;
+    dates::local(date.and_time(NaiveTime::MIN)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use lastodo_config::ColumnList;
+
+    use super::*;
+    use crate::app::KanbanStatus;
+
+    fn at(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn export_and_import_round_trip() {
+        let columns = Columns::new(&ColumnList::default());
+        let open = Task {
+            title: String::from("Ship it"),
+            kanban_status: KanbanStatus::new("Doing"),
+            due: Some(at(2024, 6, 1, 14, 30)),
+            created_at: Some(at(2024, 5, 20, 9, 0)),
+            priority: Some(Priority::try_from('A').unwrap()),
+            projects: vec![String::from("launch")],
+            contexts: vec![String::from("work")],
+            tags: vec![
+                (String::from("rec"), String::from("1w")),
+                (String::from("rec"), String::from("2w")),
+            ],
+            ..Task::default()
+        };
+        let done = Task {
+            title: String::from("Write the notes"),
+            kanban_status: KanbanStatus::new("Done"),
+            due: Some(at(2024, 5, 31, 0, 0)),
+            moved_at: Some(at(2024, 5, 30, 0, 0)),
+            created_at: Some(at(2024, 5, 1, 0, 0)),
+            priority: Some(Priority::try_from('B').unwrap()),
+            ..Task::default()
+        };
+
+        let mut board = Board::default();
+        for task in [&open, &done] {
+            board
+                .task_list
+                .entry(task.kanban_status.clone())
+                .or_default()
+                .push(task.clone());
+        }
+
+        let exported = export(&board, &columns).unwrap();
+        let imported = import(&exported, &columns).unwrap();
+
+        for ours in [&open, &done] {
+            let mut theirs = imported.task_list[&ours.kanban_status]
+                .iter()
+                .find(|task| task.id == ours.id)
+                .unwrap_or_else(|| panic!("{} is missing from:\n{}", ours.title, exported))
+                .clone();
+            reconcile(ours, &mut theirs, &columns);
+            assert_eq!(&theirs, ours, "exported as:\n{}", exported);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+/// How well a query matched some text
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher is better
+    pub score: i64,
+    /// The char indices in the text that matched the query, in order
+    pub indices: Vec<usize>,
+}
+
+/// Match `query` against `text` as a case-insensitive subsequence. Matches that are contiguous
+/// or start at word boundaries score higher, and an empty query matches everything.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let text: Vec<char> = text.chars().collect();
+    let mut indices = Vec::new();
+    let mut score = 0;
+    let mut start = 0;
+
+    for query_char in query.chars().filter(|c| !c.is_whitespace()) {
+        let offset = text[start..]
+            .iter()
+            .position(|c| c.to_lowercase().eq(query_char.to_lowercase()))?;
+        let index = start + offset;
+
+        score += 1;
+        if indices.last().is_some_and(|&last| last + 1 == index) {
+            score += 5;
+        }
+        if index == 0 || !text[index - 1].is_alphanumeric() {
+            score += 3;
+        }
+        // Penalize gaps, but not so much that a long match beats a short one
+        score -= offset.min(3) as i64;
+
+        indices.push(index);
+        start = index + 1;
+    }
+
+    Some(FuzzyMatch { score, indices })
+}
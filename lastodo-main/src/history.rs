@@ -0,0 +1,235 @@
+//! Undo and redo of changes to the board.
+//!
+//! Every action that changes the board records a [`Change`] that knows how to put things back.
+//! Tasks are found by ID when a change is undone or redone, so it doesn't matter what happened
+//! to the rest of the board in between.
+
+use std::collections::VecDeque;
+
+use crate::app::{App, ArchivedTask, CurrentTask, KanbanStatus, Task, TaskId};
+
+/// One reversible change to the board
+#[derive(Clone, Debug)]
+pub enum Change {
+    /// A task was added at `index` of its column
+    Create { task: Task, index: usize },
+    /// A task's fields were changed where it stands
+    Edit { before: Task, after: Task },
+    /// A task went from `from` in its old column to `to` in its new one, which may be the same
+    Move {
+        before: Task,
+        from: usize,
+        after: Task,
+        to: usize,
+    },
+    /// A task was taken from `index` of its column into the archive
+    Delete {
+        archived: ArchivedTask,
+        index: usize,
+    },
+    /// A task was taken from `archive_index` of the archive and put back at `index`
+    Restore {
+        archived: ArchivedTask,
+        archive_index: usize,
+        index: usize,
+    },
+}
+
+impl Change {
+    /// What the change did, for telling the user what was undone or redone
+    pub fn describe(&self) -> String {
+        match self {
+            Change::Create { task, .. } => format!("adding \"{}\"", task.title),
+            Change::Edit { after, .. } => format!("editing \"{}\"", after.title),
+            Change::Move { before, after, .. } if before.kanban_status == after.kanban_status => {
+                format!("reordering \"{}\"", after.title)
+            }
+            Change::Move { after, .. } => {
+                format!("moving \"{}\" to {}", after.title, after.kanban_status)
+            }
+            Change::Delete { archived, .. } => format!("deleting \"{}\"", archived.task.title),
+            Change::Restore { archived, .. } => format!("restoring \"{}\"", archived.task.title),
+        }
+    }
+
+    /// Do the change again, after it was undone
+    fn redo(&self, app: &mut App) -> Result<(), String> {
+        match self {
+            Change::Create { task, index } => app.put_task(task.clone(), *index),
+            Change::Edit { after, .. } => {
+                let (_, index) = app.take_task(after.id)?;
+                app.put_task(after.clone(), index);
+            }
+            Change::Move { after, to, .. } => {
+                app.take_task(after.id)?;
+                app.put_task(after.clone(), *to);
+            }
+            Change::Delete { archived, .. } => {
+                app.take_task(archived.task.id)?;
+                app.archive.push(archived.clone());
+            }
+            Change::Restore {
+                archived, index, ..
+            } => {
+                take_archived(app, archived.task.id)?;
+                app.put_task(archived.task.clone(), *index);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Put things back the way they were before the change
+    fn undo(&self, app: &mut App) -> Result<(), String> {
+        match self {
+            Change::Create { task, .. } => {
+                app.take_task(task.id)?;
+            }
+            Change::Edit { before, .. } => {
+                let (_, index) = app.take_task(before.id)?;
+                app.put_task(before.clone(), index);
+            }
+            Change::Move { before, from, .. } => {
+                app.take_task(before.id)?;
+                app.put_task(before.clone(), *from);
+            }
+            Change::Delete { archived, index } => {
+                take_archived(app, archived.task.id)?;
+                app.put_task(archived.task.clone(), *index);
+            }
+            Change::Restore {
+                archived,
+                archive_index,
+                ..
+            } => {
+                app.take_task(archived.task.id)?;
+                let archive_index = (*archive_index).min(app.archive.len());
+                app.archive.insert(archive_index, archived.clone());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The task the change was about, which the cursor follows
+    fn task_id(&self) -> TaskId {
+        match self {
+            Change::Create { task, .. } => task.id,
+            Change::Edit { after, .. } => after.id,
+            Change::Move { after, .. } => after.id,
+            Change::Delete { archived, .. } => archived.task.id,
+            Change::Restore { archived, .. } => archived.task.id,
+        }
+    }
+}
+
+/// Take a task out of the archive
+fn take_archived(app: &mut App, id: TaskId) -> Result<ArchivedTask, String> {
+    let index = app
+        .archive
+        .iter()
+        .position(|archived| archived.task.id == id)
+        .ok_or("The task is no longer in the archive.")?;
+
+    Ok(app.archive.remove(index))
+}
+
+/// The changes that can be undone, and the ones that were undone and can be redone
+#[derive(Debug)]
+pub struct History {
+    done: VecDeque<Change>,
+    undone: Vec<Change>,
+    depth: usize,
+}
+
+impl History {
+    /// Remember up to `depth` changes
+    pub fn new(depth: usize) -> History {
+        History {
+            done: VecDeque::new(),
+            undone: Vec::new(),
+            depth,
+        }
+    }
+
+    /// Remember a change that was just made. Whatever was undone before it can't be redone.
+    pub fn record(&mut self, change: Change) {
+        self.undone.clear();
+        self.done.push_back(change);
+        while self.done.len() > self.depth {
+            self.done.pop_front();
+        }
+    }
+}
+
+impl Default for History {
+    fn default() -> History {
+        History::new(100)
+    }
+}
+
+impl App {
+    /// Undo the last change to the board, and say what it was
+    pub fn undo(&mut self) -> Result<(), String> {
+        let change = self
+            .history
+            .done
+            .pop_back()
+            .ok_or("There is nothing to undo.")?;
+
+        change.undo(self)?;
+        self.message = format!("Undid {}.", change.describe());
+        self.follow_task(change.task_id());
+        self.history.undone.push(change);
+
+        Ok(())
+    }
+
+    /// Redo the last change that was undone, and say what it was
+    pub fn redo(&mut self) -> Result<(), String> {
+        let change = self
+            .history
+            .undone
+            .pop()
+            .ok_or("There is nothing to redo.")?;
+
+        change.redo(self)?;
+        self.message = format!("Redid {}.", change.describe());
+        self.follow_task(change.task_id());
+        self.history.done.push_back(change);
+
+        Ok(())
+    }
+
+    /// Take a task off the board, saying where it was
+    fn take_task(&mut self, id: TaskId) -> Result<(KanbanStatus, usize), String> {
+        let (status, index) = self
+            .locate(id)
+            .ok_or("The task is no longer on the board.")?;
+        self.task_list[&status].remove(index);
+
+        Ok((status, index))
+    }
+
+    /// Put a task at `index` of its column, or at the bottom if the column got shorter
+    fn put_task(&mut self, task: Task, index: usize) {
+        let tasks = self
+            .task_list
+            .entry(task.kanban_status.clone())
+            .or_default();
+        tasks.insert(index.min(tasks.len()), task);
+    }
+
+    /// Move the cursor onto a task if it's on the board, or keep it in bounds if it isn't
+    fn follow_task(&mut self, id: TaskId) {
+        match self.locate(id) {
+            Some((status, index)) => {
+                self.cur_task = Some(CurrentTask {
+                    status,
+                    index: index as i16,
+                })
+            }
+            None => self.clamp_cursor(),
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashMap;
+
+use lastodo_config::{Config, ConfigError, KeyChord, KeySequence, Preset};
+
+use crate::actions::Registry;
+
+/// Which set of bindings applies, based on what's on screen
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum KeyContext {
+    Main,
+    Editing,
+    Insert,
+    Palette,
+    Confirm,
+    Archive,
+    Boards,
+    Search,
+    Filter,
+}
+
+/// What a sequence of key presses means
+#[derive(Clone, Debug, PartialEq)]
+pub enum Resolution {
+    /// The keys trigger this action
+    Action(String),
+    /// The keys are the start of a longer binding, so wait for more
+    Pending,
+    /// Nothing is bound to these keys
+    Unbound,
+}
+
+/// How the arrow-key preset differs from the default, vim-like, bindings
+const ARROWS_PRESET: &[(KeyContext, &str, &[&str])] = &[
+    (KeyContext::Main, "save_board", &["<C-s>"]),
+    (KeyContext::Main, "cursor_left", &["<Left>"]),
+    (KeyContext::Main, "cursor_right", &["<Right>"]),
+    (KeyContext::Main, "cursor_down", &["<Down>"]),
+    (KeyContext::Main, "cursor_up", &["<Up>"]),
+    (KeyContext::Main, "cursor_top", &["<Home>"]),
+    (KeyContext::Main, "cursor_bottom", &["<End>"]),
+    (KeyContext::Main, "edit_task", &["<Enter>"]),
+    (KeyContext::Main, "move_task_left", &["<S-Left>"]),
+    (KeyContext::Main, "move_task_right", &["<S-Right>"]),
+    (KeyContext::Main, "move_task_up", &["<S-Up>"]),
+    (KeyContext::Main, "move_task_down", &["<S-Down>"]),
+    (KeyContext::Main, "move_task_top", &["<S-Home>"]),
+    (KeyContext::Main, "move_task_bottom", &["<S-End>"]),
+    (KeyContext::Main, "delete_task", &["<Del>"]),
+    (KeyContext::Main, "undo", &["<C-z>"]),
+    (KeyContext::Main, "redo", &["<C-y>"]),
+    (KeyContext::Archive, "archive_cursor_up", &["<Up>"]),
+    (KeyContext::Archive, "archive_cursor_down", &["<Down>"]),
+    (KeyContext::Archive, "restore_task", &["<Enter>"]),
+    (KeyContext::Archive, "close_archive", &["<Esc>"]),
+    (KeyContext::Boards, "boards_cursor_up", &["<Up>"]),
+    (KeyContext::Boards, "boards_cursor_down", &["<Down>"]),
+    (KeyContext::Boards, "close_boards", &["<Esc>"]),
+    (KeyContext::Editing, "insert_mode", &["<Enter>"]),
+    (KeyContext::Editing, "save_task", &["<C-s>"]),
+    (KeyContext::Editing, "quit_editing", &["<Esc>"]),
+    (KeyContext::Editing, "force_quit_editing", &["<C-q>"]),
+    (KeyContext::Editing, "next_field", &["<Tab>", "<Down>"]),
+    (KeyContext::Editing, "prev_field", &["<S-Tab>", "<Up>"]),
+];
+
+/// Resolves key presses to action names, per context
+#[derive(Debug, Default)]
+pub struct Keymap {
+    bindings: HashMap<KeyContext, Vec<(KeySequence, String)>>,
+}
+
+impl Keymap {
+    /// Start from each action's default binding, apply the configured preset, then apply the
+    /// overrides from the config on top. An override replaces every other binding of that
+    /// action, and takes its keys away from any other action they were bound to.
+    pub fn from_config(config: &Config, registry: &Registry) -> Result<Keymap, ConfigError> {
+        let mut keymap = Keymap::default();
+        for action in registry.iter() {
+            for keys in action.default_binding() {
+                let sequence = keys.parse().expect("default bindings are valid");
+                keymap.bind(action.context(), sequence, action.name());
+            }
+        }
+
+        if config.keys.preset == Preset::Arrows {
+            for (context, action, keys) in ARROWS_PRESET {
+                let sequences: Vec<KeySequence> = keys
+                    .iter()
+                    .map(|keys| keys.parse().expect("preset bindings are valid"))
+                    .collect();
+                keymap.rebind(*context, action, &sequences);
+            }
+        }
+
+        for (context, overrides) in [
+            (KeyContext::Main, &config.keys.main),
+            (KeyContext::Editing, &config.keys.editing),
+            (KeyContext::Insert, &config.keys.insert),
+            (KeyContext::Palette, &config.keys.palette),
+            (KeyContext::Confirm, &config.keys.confirm),
+            (KeyContext::Archive, &config.keys.archive),
+            (KeyContext::Boards, &config.keys.boards),
+            (KeyContext::Search, &config.keys.search),
+            (KeyContext::Filter, &config.keys.filter),
+        ] {
+            for (action, keys) in overrides {
+                if registry.get(action.get_ref()).is_none() {
+                    return Err(config.error_at(
+                        action.span(),
+                        format!("unknown action `{}`", action.get_ref()),
+                    ));
+                }
+
+                keymap.rebind(context, action.get_ref(), &keys.0);
+            }
+        }
+
+        Ok(keymap)
+    }
+
+    fn bind(&mut self, context: KeyContext, sequence: KeySequence, action: &str) {
+        self.bindings
+            .entry(context)
+            .or_default()
+            .push((sequence, action.to_string()));
+    }
+
+    /// Bind `action` to exactly `sequences`, taking them away from whatever had them
+    fn rebind(&mut self, context: KeyContext, action: &str, sequences: &[KeySequence]) {
+        let bindings = self.bindings.entry(context).or_default();
+        bindings.retain(|(sequence, bound)| bound != action && !sequences.contains(sequence));
+
+        for sequence in sequences {
+            self.bind(context, sequence.clone(), action);
+        }
+    }
+
+    /// The keys bound to `action` in `context`, for showing to the user
+    pub fn keys_for(&self, context: KeyContext, action: &str) -> Vec<&KeySequence> {
+        self.bindings
+            .get(&context)
+            .into_iter()
+            .flatten()
+            .filter(|(_, bound)| bound == action)
+            .map(|(sequence, _)| sequence)
+            .collect()
+    }
+
+    /// The first of the keys bound to `action` in `context`, for hints like `w: Save`
+    pub fn first_key(&self, context: KeyContext, action: &str) -> Option<String> {
+        self.keys_for(context, action)
+            .first()
+            .map(|keys| keys.to_string())
+    }
+
+    /// Figure out what `keys`, pressed in order, mean in `context`
+    pub fn resolve(&self, context: KeyContext, keys: &[KeyChord]) -> Resolution {
+        let bindings = self.bindings.get(&context).map_or(&[][..], Vec::as_slice);
+
+        let mut exact = None;
+        let mut is_prefix = false;
+        for (sequence, action) in bindings {
+            if sequence.0 == keys {
+                exact = Some(action);
+            } else if sequence.0.starts_with(keys) {
+                is_prefix = true;
+            }
+        }
+
+        match (exact, is_prefix) {
+            (_, true) => Resolution::Pending,
+            (Some(action), false) => Resolution::Action(action.clone()),
+            (None, false) => Resolution::Unbound,
+        }
+    }
+}
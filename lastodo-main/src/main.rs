@@ -1,17 +1,116 @@
 mod actions;
 mod app;
+mod cli;
+mod columns;
+mod dates;
 mod errors;
+mod filter;
+mod formats;
+mod fuzzy;
+mod history;
+mod keymap;
+mod palette;
+mod search;
+mod sort;
+mod store;
 mod tui;
 mod ui;
 
+use actions::Registry;
 use app::App;
+use clap::Parser;
+use cli::{Cli, Command, ConfigCommand};
+use columns::Columns;
+use keymap::Keymap;
+use lastodo_config::Config;
+use std::env;
 use std::error::Error;
+use std::process;
+use std::rc::Rc;
+use store::{FileStore, TaskStore};
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
+    let config_path = cli
+        .config
+        .or_else(Config::default_path)
+        .ok_or("could not find a config directory, use --config")?;
+
+    if let Some(Command::Config {
+        command: ConfigCommand::Check,
+    }) = cli.command
+    {
+        process::exit(if cli::check_config(&config_path) {
+            0
+        } else {
+            1
+        });
+    }
+
+    if let Some(Command::Init) = cli.command {
+        if let Err(err) = cli::init() {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    let config = match Config::load(&config_path) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    };
+
+    // A project's own boards take the place of the global ones, like a git repository
+    let project_dir = if cli.global {
+        None
+    } else {
+        env::current_dir()
+            .ok()
+            .and_then(|dir| FileStore::find_project_dir(&dir))
+    };
+    let data_dir = project_dir
+        .as_deref()
+        .or(config.storage.data_dir.as_deref());
+    let store = match FileStore::in_data_dir(data_dir, cli.board.as_deref()) {
+        Ok(store) => store,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    };
+    // Commands work on the board directly and leave the terminal alone
+    if let Some(command) = &cli.command {
+        if let Err(err) = cli::run(command, &store, &config) {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+        return Ok(());
+    }
+
+    // Load the board before taking over the terminal so load errors are readable. Which
+    // actions there are depends on the board's columns, so the keymap has to wait for it.
+    let board = store.load()?;
+    let columns = Columns::for_board(&board, &config);
+    let registry = Rc::new(Registry::new(&columns, &config));
+    let keymap = match Keymap::from_config(&config, &registry) {
+        Ok(keymap) => keymap,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        }
+    };
+
     let _ = errors::install_hooks();
+
+    let mut app = App::new(Box::new(store), board, columns, config, registry, keymap);
+
     let mut terminal = tui::init()?;
-    let _ = App::new().run(&mut terminal);
+    let result = app.run(&mut terminal);
     tui::restore()?;
 
+    result?;
     Ok(())
 }
@@ -0,0 +1,34 @@
+use crate::actions::Action;
+use crate::app::App;
+use crate::fuzzy::{fuzzy_match, FuzzyMatch};
+use crate::keymap::KeyContext;
+
+/// What has been typed into the command palette
+#[derive(Clone, Debug, Default)]
+pub struct PaletteState {
+    pub query: String,
+    /// Index into the matches, not into the registry
+    pub selected: usize,
+}
+
+impl App {
+    /// The actions matching the palette query, best first. Actions that only make sense inside
+    /// the palette aren't listed.
+    pub fn palette_matches(&self) -> Vec<(&dyn Action, FuzzyMatch)> {
+        let query = self
+            .palette
+            .as_ref()
+            .map_or("", |palette| palette.query.as_str());
+
+        let mut matches: Vec<_> = self
+            .registry
+            .iter()
+            .filter(|action| action.context() != KeyContext::Palette)
+            .filter_map(|action| Some((action, fuzzy_match(query, action.name())?)))
+            .collect();
+
+        // Stable, so equally good matches stay in registry order
+        matches.sort_by_key(|(_, m)| -m.score);
+        matches
+    }
+}
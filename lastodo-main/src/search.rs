@@ -0,0 +1,135 @@
+use crate::app::{App, CurrentTask, KanbanStatus, TaskId};
+use crate::fuzzy::fuzzy_match;
+
+/// What has been searched for, and where the cursor was before
+#[derive(Clone, Debug, Default)]
+pub struct SearchState {
+    pub query: String,
+    /// Where to go back to if the search is cancelled
+    pub origin: Option<CurrentTask>,
+}
+
+/// A task that matches the search
+#[derive(Clone, Debug)]
+pub struct SearchHit {
+    pub id: TaskId,
+    pub status: KanbanStatus,
+    pub index: usize,
+    /// Where the task is shown in its column, which is different from `index` if it's sorted
+    /// or filtered
+    pub row: usize,
+    pub score: i64,
+    /// The char indices in the title that matched. Empty if only the description matched.
+    pub indices: Vec<usize>,
+}
+
+impl App {
+    /// The tasks matching the search, in board order: column by column, top to bottom as the
+    /// columns are shown. Tasks the filter hides aren't searched, and an empty query matches
+    /// nothing.
+    pub fn search_hits(&self) -> Vec<SearchHit> {
+        let Some(query) = self
+            .search
+            .as_ref()
+            .map(|search| search.query.as_str())
+            .filter(|query| !query.trim().is_empty())
+        else {
+            return Vec::new();
+        };
+
+        let mut hits = Vec::new();
+        for (status, tasks) in &self.task_list {
+            for (row, index) in self.visible_tasks(status).into_iter().enumerate() {
+                let task = &tasks[index];
+                let title = fuzzy_match(query, &task.title);
+                let description = fuzzy_match(query, &task.description);
+                let (score, indices) = match (title, description) {
+                    (Some(title), Some(description)) if description.score > title.score => {
+                        (description.score, title.indices)
+                    }
+                    (Some(title), _) => (title.score, title.indices),
+                    (None, Some(description)) => (description.score, Vec::new()),
+                    (None, None) => continue,
+                };
+
+                hits.push(SearchHit {
+                    id: task.id,
+                    status: status.clone(),
+                    index,
+                    row,
+                    score,
+                    indices,
+                });
+            }
+        }
+
+        hits
+    }
+
+    /// Put the cursor on the best match while the query is being typed, or back where it
+    /// was if nothing matches
+    pub fn jump_to_best_hit(&mut self) {
+        // Stable, so the first of equally good matches wins
+        let mut hits = self.search_hits();
+        hits.sort_by_key(|hit| -hit.score);
+
+        match hits.first() {
+            Some(hit) => self.jump_to_hit(hit),
+            None => {
+                self.cur_task = self
+                    .search
+                    .as_ref()
+                    .and_then(|search| search.origin.clone())
+            }
+        }
+    }
+
+    /// Put the cursor on the match after the highlighted task, or before it if `forward` is
+    /// false, going round at the ends of the board
+    pub fn jump_to_next_hit(&mut self, forward: bool) -> Result<(), String> {
+        let hits = self.search_hits();
+        if hits.is_empty() {
+            return Err(match &self.search {
+                Some(search) => format!("No tasks match \"{}\".", search.query),
+                None => String::from("Search with / first."),
+            });
+        }
+
+        // Where a task is in board order
+        let position = |status: &KanbanStatus, row: usize| {
+            (self.task_list.get_index_of(status).unwrap_or(0), row)
+        };
+        let cursor = self.cur_task.as_ref().map(|cur_task| {
+            let row = self
+                .visible_tasks(&cur_task.status)
+                .iter()
+                .position(|&index| index as i16 == cur_task.index)
+                .unwrap_or(0);
+            position(&cur_task.status, row)
+        });
+        let order =
+            |hit: &SearchHit| cursor.map(|cursor| position(&hit.status, hit.row).cmp(&cursor));
+
+        let next = if forward {
+            hits.iter()
+                .position(|hit| order(hit).is_some_and(|order| order.is_gt()))
+                .unwrap_or(0)
+        } else {
+            hits.iter()
+                .rposition(|hit| order(hit).is_some_and(|order| order.is_lt()))
+                .unwrap_or(hits.len() - 1)
+        };
+
+        self.jump_to_hit(&hits[next]);
+        self.message = format!("Match {} of {}.", next + 1, hits.len());
+
+        Ok(())
+    }
+
+    fn jump_to_hit(&mut self, hit: &SearchHit) {
+        self.cur_task = Some(CurrentTask {
+            status: hit.status.clone(),
+            index: hit.index as i16,
+        });
+    }
+}
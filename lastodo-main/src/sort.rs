@@ -0,0 +1,120 @@
+//! Orders a column can be shown in. The board always keeps its tasks in manual order, the
+//! order they were put in and moved to, and the other modes only change how a column is
+//! shown. So going back to manual sorting brings back the order the tasks were left in.
+
+use std::cmp::{Ordering, Reverse};
+
+use serde::{Deserialize, Serialize};
+
+use crate::app::{App, KanbanStatus, Task};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortMode {
+    #[default]
+    Manual,
+    /// Soonest due first
+    Due,
+    /// Newest first
+    Created,
+    /// Most recently edited or moved first
+    Updated,
+    /// `A` first
+    Priority,
+    /// Alphabetical, ignoring case
+    Title,
+}
+
+impl SortMode {
+    /// The mode after this one, going round to manual after the last
+    pub fn next(self) -> SortMode {
+        match self {
+            SortMode::Manual => SortMode::Due,
+            SortMode::Due => SortMode::Created,
+            SortMode::Created => SortMode::Updated,
+            SortMode::Updated => SortMode::Priority,
+            SortMode::Priority => SortMode::Title,
+            SortMode::Title => SortMode::Manual,
+        }
+    }
+
+    /// The mode's name, as it's shown in column titles and saved with the board
+    pub fn label(self) -> &'static str {
+        match self {
+            SortMode::Manual => "manual",
+            SortMode::Due => "due",
+            SortMode::Created => "created",
+            SortMode::Updated => "updated",
+            SortMode::Priority => "priority",
+            SortMode::Title => "title",
+        }
+    }
+
+    /// How a column sorted this way is, for messages like "Doing is sorted by due date."
+    pub fn description(self) -> &'static str {
+        match self {
+            SortMode::Manual => "by hand",
+            SortMode::Due => "by due date",
+            SortMode::Created => "by when tasks were created",
+            SortMode::Updated => "by when tasks were last updated",
+            SortMode::Priority => "by priority",
+            SortMode::Title => "by title",
+        }
+    }
+
+    /// The positions of `tasks` in this order. The sort is stable, so tasks that compare the
+    /// same stay in manual order.
+    pub fn order(self, tasks: &[Task]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..tasks.len()).collect();
+        if self != SortMode::Manual {
+            order.sort_by(|&a, &b| self.compare(&tasks[a], &tasks[b]));
+        }
+        order
+    }
+
+    /// Tasks without what's being sorted on go last
+    fn compare(self, a: &Task, b: &Task) -> Ordering {
+        // Tasks saved before they had an update time were last updated when they were created
+        let updated = |task: &Task| task.updated_at.or(task.created_at);
+
+        match self {
+            SortMode::Manual => Ordering::Equal,
+            SortMode::Due => missing_last(a.due, b.due),
+            SortMode::Created => missing_last(a.created_at.map(Reverse), b.created_at.map(Reverse)),
+            SortMode::Updated => missing_last(updated(a).map(Reverse), updated(b).map(Reverse)),
+            SortMode::Priority => missing_last(a.priority, b.priority),
+            SortMode::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+        }
+    }
+}
+
+fn missing_last<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+impl App {
+    /// How the `status` column is sorted
+    pub fn sort_mode(&self, status: &KanbanStatus) -> SortMode {
+        self.sort_modes.get(status).copied().unwrap_or_default()
+    }
+
+    /// Show the `status` column sorted by `mode` from now on
+    pub fn set_sort_mode(&mut self, status: KanbanStatus, mode: SortMode) {
+        match mode {
+            SortMode::Manual => self.sort_modes.shift_remove(&status),
+            mode => self.sort_modes.insert(status, mode),
+        };
+    }
+
+    /// The positions of the tasks in `status`, in the order the column shows them
+    pub fn sorted_tasks(&self, status: &KanbanStatus) -> Vec<usize> {
+        self.task_list
+            .get(status)
+            .map_or_else(Vec::new, |tasks| self.sort_mode(status).order(tasks))
+    }
+}
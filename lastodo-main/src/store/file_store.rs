@@ -0,0 +1,166 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use super::{Board, TaskStore};
+
+/// The board that's opened when none is asked for. It's the one from before there were
+/// several boards, so it keeps its old file.
+const DEFAULT_BOARD: &str = "default";
+const BOARD_FILE_NAME: &str = "board.json";
+/// Where the other boards are kept, one file each, inside the data directory
+const BOARDS_DIR_NAME: &str = "boards";
+/// A directory with this name keeps the boards of the project it's in, the way `.git` keeps
+/// its history. It's laid out like the data directory.
+pub const PROJECT_DIR_NAME: &str = ".lastodo";
+
+/// A store that keeps each board as a JSON file
+#[derive(Debug)]
+pub struct FileStore {
+    data_dir: PathBuf,
+    name: String,
+    path: PathBuf,
+}
+
+impl FileStore {
+    /// The board called `name` in `data_dir`. The default board is `board.json`, and the
+    /// others are `boards/<name>.json`.
+    pub fn new(data_dir: PathBuf, name: &str) -> io::Result<FileStore> {
+        let name = name.trim();
+        if name.is_empty()
+            || name.starts_with('.')
+            || name.contains(['/', '\\'])
+            || name.chars().any(char::is_control)
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("`{}` can't be the name of a board", name),
+            ));
+        }
+
+        let path = if name == DEFAULT_BOARD {
+            data_dir.join(BOARD_FILE_NAME)
+        } else {
+            data_dir
+                .join(BOARDS_DIR_NAME)
+                .join(format!("{}.json", name))
+        };
+
+        Ok(FileStore {
+            data_dir,
+            name: name.to_string(),
+            path,
+        })
+    }
+
+    /// The board called `name`, or the default board, in `data_dir`. Boards are kept under the
+    /// XDG data directory (i.e. `~/.local/share/lastodo/`) if no directory was configured.
+    pub fn in_data_dir(data_dir: Option<&Path>, name: Option<&str>) -> io::Result<FileStore> {
+        let data_dir = match data_dir {
+            Some(data_dir) => data_dir.to_path_buf(),
+            None => dirs::data_dir()
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::NotFound, "could not find a data directory")
+                })?
+                .join("lastodo"),
+        };
+
+        FileStore::new(data_dir, name.unwrap_or(DEFAULT_BOARD))
+    }
+
+    /// The project directory in `dir`, or else in the closest parent of `dir` that has one
+    pub fn find_project_dir(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors()
+            .map(|dir| dir.join(PROJECT_DIR_NAME))
+            .find(|project_dir| project_dir.is_dir())
+    }
+
+    /// Start keeping boards for the project in `dir`, beginning with an empty default board
+    pub fn init(dir: &Path) -> io::Result<FileStore> {
+        let project_dir = dir.join(PROJECT_DIR_NAME);
+        if project_dir.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", project_dir.display()),
+            ));
+        }
+
+        let store = FileStore::new(project_dir, DEFAULT_BOARD)?;
+        store.save(&Board::default())?;
+
+        Ok(store)
+    }
+}
+
+impl TaskStore for FileStore {
+    fn load(&self) -> io::Result<Board> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Board::default()),
+            Err(err) => return Err(err),
+        };
+
+        // Tasks from before there were IDs get new ones here, which are kept by the next save.
+        // Loading doesn't write, as the board may be read-only or committed along with a project.
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    fn save(&self, board: &Board) -> io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // Write to a temporary file first so a crash halfway through doesn't eat the board
+        let tmp_path = self.path.with_extension("json.tmp");
+        fs::write(&tmp_path, serde_json::to_string_pretty(board)?)?;
+        fs::rename(tmp_path, &self.path)
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn boards(&self) -> io::Result<Vec<String>> {
+        let mut boards = Vec::new();
+        match fs::read_dir(self.data_dir.join(BOARDS_DIR_NAME)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let path = entry?.path();
+                    if path
+                        .extension()
+                        .is_some_and(|extension| extension == "json")
+                    {
+                        boards.extend(
+                            path.file_stem()
+                                .and_then(|stem| stem.to_str())
+                                .map(String::from),
+                        );
+                    }
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err),
+        }
+        boards.retain(|board| board != DEFAULT_BOARD);
+        boards.sort_by_key(|board| board.to_lowercase());
+
+        // The default board is always there, even before it's saved, and so is this one
+        boards.insert(0, String::from(DEFAULT_BOARD));
+        if !boards.contains(&self.name) {
+            boards.push(self.name.clone());
+        }
+
+        Ok(boards)
+    }
+
+    fn open(&self, name: &str) -> io::Result<Box<dyn TaskStore>> {
+        Ok(Box::new(FileStore::new(self.data_dir.clone(), name)?))
+    }
+
+    fn delete(&self) -> io::Result<()> {
+        match fs::remove_file(&self.path) {
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+}
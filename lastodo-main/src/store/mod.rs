@@ -0,0 +1,86 @@
+pub mod file_store;
+
+use std::fmt::Debug;
+use std::io;
+
+use indexmap::IndexMap;
+use lastodo_config::ColumnList;
+use serde::{Deserialize, Serialize};
+
+use crate::app::{ArchivedTask, KanbanStatus, Task};
+use crate::columns::Columns;
+use crate::sort::SortMode;
+
+pub use file_store::FileStore;
+
+/// Everything that gets written to disk for a board
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Board {
+    pub task_list: IndexMap<KanbanStatus, Vec<Task>>,
+    #[serde(default)]
+    pub archive: Vec<ArchivedTask>,
+    /// The board's own columns, if it doesn't use the ones from the config
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub columns: Option<ColumnList>,
+    /// How each column is sorted, for the ones that aren't sorted by hand
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub sort_modes: IndexMap<KanbanStatus, SortMode>,
+}
+
+impl Board {
+    /// Find a task by its ID, or by the start of it as long as only one task matches. Gives the
+    /// column and position of the task.
+    pub fn find(&self, id: &str) -> Result<(KanbanStatus, usize), String> {
+        let id = id.trim().to_uppercase();
+        let mut matches = self.task_list.iter().flat_map(|(status, tasks)| {
+            tasks
+                .iter()
+                .enumerate()
+                .filter(|(_, task)| task.id.to_string().starts_with(&id))
+                .map(move |(index, _)| (status.clone(), index))
+        });
+        match (matches.next(), matches.next()) {
+            (Some(found), None) if !id.is_empty() => Ok(found),
+            (Some(_), Some(_)) => Err(format!(
+                "more than one task has an ID starting with `{}`",
+                id
+            )),
+            _ => Err(format!("no task has the ID `{}`", id)),
+        }
+    }
+
+    /// Make sure every column exists, in the order of `columns`, even if the stored board was
+    /// written by an older version, edited by hand, or its columns were changed since.
+    pub fn normalize(mut self, columns: &Columns) -> Board {
+        let mut task_list = IndexMap::new();
+        for status in columns.statuses() {
+            let tasks = self.task_list.shift_remove(status).unwrap_or_default();
+            task_list.insert(status.clone(), tasks);
+        }
+        task_list.extend(self.task_list.drain(..));
+
+        Board { task_list, ..self }
+    }
+}
+
+/// A place where boards are loaded from and saved to
+pub trait TaskStore: Debug {
+    /// Load the board. A store that has never been saved to returns an empty board.
+    fn load(&self) -> io::Result<Board>;
+
+    /// Replace the stored board with `board`
+    fn save(&self, board: &Board) -> io::Result<()>;
+
+    /// The name of the board this store holds
+    fn name(&self) -> &str;
+
+    /// The names of the boards kept in the same place as this one, including this one
+    fn boards(&self) -> io::Result<Vec<String>>;
+
+    /// Another board kept in the same place as this one. It's created when it's first saved.
+    fn open(&self, name: &str) -> io::Result<Box<dyn TaskStore>>;
+
+    /// Remove the stored board entirely
+    #[allow(dead_code)]
+    fn delete(&self) -> io::Result<()>;
+}
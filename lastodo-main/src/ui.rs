@@ -1,11 +1,27 @@
+use chrono::{DateTime, Local};
 use ratatui::layout::{Constraint, Direction, Layout};
 use ratatui::prelude::Rect;
 use ratatui::style::*;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph, Row, Table};
+use ratatui::widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState, Wrap};
 use ratatui::Frame;
 
-use crate::app::{App, CurrentScreen, TaskEditMode, TaskEditState, TaskField};
+use lastodo_config::Color as ConfigColor;
+
+use crate::app::{App, Confirmation, CurrentScreen, TaskField};
+use crate::keymap::KeyContext;
+use crate::sort::SortMode;
+
+/// How due dates are spelled out in the editor
+const DUE_PREVIEW_FORMAT: &str = "%a %Y-%m-%d %H:%M";
+
+/// Describe a due date, which most tasks don't have
+fn format_due(due: Option<DateTime<Local>>, format: &str) -> String {
+    match due {
+        Some(due) => due.format(format).to_string(),
+        None => "No due date".to_string(),
+    }
+}
 
 /// helper function to create a centered rect using up certain percentage of the available rect `r`
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
@@ -30,6 +46,35 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1] // Return the middle chunk
 }
 
+/// The actions whose keys are shown at the bottom of a screen, with what to call them. Actions
+/// that share a name, like moving the cursor each way, have their keys shown together.
+type KeyHints = &'static [(&'static [&'static str], &'static str)];
+
+/// Convert a color from the config file into its ratatui equivalent
+fn to_color(color: ConfigColor) -> Color {
+    match color {
+        ConfigColor::Reset => Color::Reset,
+        ConfigColor::Black => Color::Black,
+        ConfigColor::Red => Color::Red,
+        ConfigColor::Green => Color::Green,
+        ConfigColor::Yellow => Color::Yellow,
+        ConfigColor::Blue => Color::Blue,
+        ConfigColor::Magenta => Color::Magenta,
+        ConfigColor::Cyan => Color::Cyan,
+        ConfigColor::Gray => Color::Gray,
+        ConfigColor::DarkGray => Color::DarkGray,
+        ConfigColor::LightRed => Color::LightRed,
+        ConfigColor::LightGreen => Color::LightGreen,
+        ConfigColor::LightYellow => Color::LightYellow,
+        ConfigColor::LightBlue => Color::LightBlue,
+        ConfigColor::LightMagenta => Color::LightMagenta,
+        ConfigColor::LightCyan => Color::LightCyan,
+        ConfigColor::White => Color::White,
+        ConfigColor::Rgb(r, g, b) => Color::Rgb(r, g, b),
+        ConfigColor::Indexed(index) => Color::Indexed(index),
+    }
+}
+
 impl App {
     pub fn render(&mut self, frame: &mut Frame) {
         // Overall layout. A header with a title, the main area, and a status bar
@@ -46,7 +91,15 @@ impl App {
         let title_block = Block::default()
             .borders(Borders::ALL)
             .style(Style::default());
-        let title = Paragraph::new("Lastodo").block(title_block);
+        let mut title_line = vec![Span::raw(self.board_name())];
+        if let Some(filter) = &self.filter {
+            title_line.push(Span::raw(" | "));
+            title_line.push(Span::styled(
+                format!("Filter: {}", filter.query()),
+                Style::default().fg(Color::Yellow),
+            ));
+        }
+        let title = Paragraph::new(Line::from(title_line)).block(title_block);
         frame.render_widget(title, chunks[0]);
 
         // Set up the main section
@@ -58,25 +111,85 @@ impl App {
             .constraints(&constraints)
             .split(chunks[1]);
 
-        let active_style = Style::default().bg(Color::LightYellow).fg(Color::Black);
+        let active_style = Style::default()
+            .bg(to_color(self.config.ui.highlight_bg))
+            .fg(to_color(self.config.ui.highlight_fg));
 
+        // While searching, the matched characters stand out and tasks that don't match fade
+        let hits = self.search_hits();
+        let is_searching = self
+            .search
+            .as_ref()
+            .is_some_and(|search| !search.query.trim().is_empty());
+        let match_style = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+
+        let cur_task_id = self.get_cur_task().map(|task| task.id);
         for (i, (status, tasks)) in self.task_list.iter().enumerate() {
-            let rows = tasks.iter().map(|task| {
-                let cur_task = self.get_cur_task();
-                let style = if cur_task.is_none() || cur_task.as_ref().unwrap() == task {
-                    active_style
-                } else {
-                    Style::default()
-                };
+            let rows = self
+                .visible_tasks(status)
+                .into_iter()
+                .map(|index| &tasks[index])
+                .map(|task| {
+                    let mut style = if cur_task_id == Some(task.id) {
+                        active_style
+                    } else {
+                        Style::default()
+                    };
+
+                    let hit = hits.iter().find(|hit| hit.id == task.id);
+                    if is_searching && hit.is_none() {
+                        style = style.add_modifier(Modifier::DIM);
+                    }
+                    let title: Vec<Span> = task
+                        .title
+                        .chars()
+                        .enumerate()
+                        .map(|(j, c)| {
+                            if hit.is_some_and(|hit| hit.indices.contains(&j)) {
+                                Span::styled(c.to_string(), match_style)
+                            } else {
+                                Span::raw(c.to_string())
+                            }
+                        })
+                        .collect();
+
+                    Row::new(vec![Cell::from(Line::from(title))]).style(style)
+                });
+
+            // Mark the focused column, so it's clear where new tasks go even when it's empty
+            let is_focused = self
+                .cur_task
+                .as_ref()
+                .is_some_and(|cur_task| cur_task.status == *status);
+            let mut title_style = if is_focused {
+                Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default()
+            };
+            if let Some(color) = self.columns.get(status).and_then(|column| column.color) {
+                title_style = title_style.fg(to_color(color));
+            }
 
-                Row::new(vec![task.title.clone()]).style(style)
-            });
+            // Columns with a limit show how full they are, in red once they're over it
+            let mut title = match self.columns.limit(status) {
+                Some(limit) => {
+                    if tasks.len() > limit {
+                        title_style = title_style.fg(Color::Red);
+                    }
+                    format!("{} ({}/{})", status, tasks.len(), limit)
+                }
+                None => status.to_string(),
+            };
+            let sort_mode = self.sort_mode(status);
+            if sort_mode != SortMode::Manual {
+                title.push_str(&format!(" [{}]", sort_mode.label()));
+            }
 
             let table = Table::new(rows, &constraints)
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .title(status.to_string()),
+                        .title(Span::styled(title, title_style)),
                 )
                 .widths([
                     Constraint::Percentage(40),
@@ -89,38 +202,105 @@ impl App {
 
         let cur_nav_text = {
             if let Some(cur_task) = self.get_cur_task() {
-                format!(
-                    "{} | Due: {}",
-                    cur_task.title,
-                    cur_task.due.format("%Y-%m-%d")
-                )
+                let due = format_due(cur_task.due, self.config.ui.date_format.as_str());
+                match cur_task.due {
+                    Some(_) => format!("{} | Due: {}", cur_task.title, due),
+                    None => format!("{} | {}", cur_task.title, due),
+                }
             } else {
                 "No task selected.".to_string()
             }
         };
-        let mode_footer =
-            Paragraph::new(Line::from(cur_nav_text)).block(Block::default().borders(Borders::ALL));
-
-        let key_hints = {
-            match self.current_screen {
-                CurrentScreen::Main => vec![
-                    Span::styled("(q)uit", Style::default().fg(Color::White)),
-                    Span::styled(" | ", Style::default().fg(Color::White)),
-                    Span::styled("(e)dit Task", Style::default().fg(Color::White)),
-                    Span::styled(" | ", Style::default().fg(Color::White)),
-                    Span::styled("h/j/k/l: Move", Style::default().fg(Color::White)),
-                    Span::styled(" | ", Style::default().fg(Color::White)),
-                    Span::styled("(a)dd Task", Style::default().fg(Color::White)),
+        // Messages from actions take the place of the task summary until the next one
+        // Prompts show what's being typed instead, along with any error in what was typed
+        let footer_line = match (&self.current_screen, &self.search) {
+            (CurrentScreen::Search, Some(search)) => Line::from(format!("/{}", search.query)),
+            (CurrentScreen::Filter, _) => {
+                let mut spans = vec![Span::raw(format!("Filter: {}", self.filter_input))];
+                if !self.message.is_empty() {
+                    spans.push(Span::styled(
+                        format!("  {}", self.message),
+                        Style::default().fg(Color::Red),
+                    ));
+                }
+                Line::from(spans)
+            }
+            _ if self.message.is_empty() => Line::from(cur_nav_text),
+            _ => Line::from(self.message.clone()),
+        };
+        let mode_footer = Paragraph::new(footer_line).block(Block::default().borders(Borders::ALL));
+
+        // Hints come from the keymap, so they follow presets and rebound keys
+        let (context, hints): (KeyContext, KeyHints) = match self.current_screen {
+            CurrentScreen::Main => (
+                KeyContext::Main,
+                &[
+                    (&["quit"], "Quit"),
+                    (&["edit_task"], "Edit Task"),
+                    (
+                        &["cursor_left", "cursor_down", "cursor_up", "cursor_right"],
+                        "Move",
+                    ),
+                    (&["add_task"], "Add Task"),
+                    (&["search"], "Search"),
+                    (&["command_palette"], "Commands"),
                 ],
-                CurrentScreen::Editing => vec![
-                    Span::styled("(q)uit", Style::default().fg(Color::White)),
-                    Span::styled(" | ", Style::default().fg(Color::White)),
-                    Span::styled("(s)ave", Style::default().fg(Color::White)),
-                    Span::styled(" | ", Style::default().fg(Color::White)),
-                    Span::styled("(c)ancel", Style::default().fg(Color::White)),
+            ),
+            CurrentScreen::Editing => (
+                KeyContext::Editing,
+                &[
+                    (&["save_task"], "Save"),
+                    (&["force_quit_editing"], "Discard"),
+                    (&["quit_editing"], "Quit"),
                 ],
-            }
+            ),
+            CurrentScreen::Palette => (
+                KeyContext::Palette,
+                &[
+                    (&["palette_run"], "Run"),
+                    (&["palette_prev", "palette_next"], "Select"),
+                    (&["palette_close"], "Close"),
+                ],
+            ),
+            CurrentScreen::Confirm => (
+                KeyContext::Confirm,
+                &[(&["confirm"], "Yes"), (&["cancel_confirm"], "No")],
+            ),
+            CurrentScreen::Archive => (
+                KeyContext::Archive,
+                &[
+                    (&["restore_task"], "Restore"),
+                    (&["archive_cursor_down", "archive_cursor_up"], "Move"),
+                    (&["close_archive"], "Quit Archive"),
+                ],
+            ),
+            CurrentScreen::Filter => (
+                KeyContext::Filter,
+                &[
+                    (&["filter_accept"], "Apply"),
+                    (&["filter_cancel"], "Cancel"),
+                ],
+            ),
+            CurrentScreen::Search => (
+                KeyContext::Search,
+                &[
+                    (&["search_accept"], "Go to Match"),
+                    (&["search_cancel"], "Cancel"),
+                ],
+            ),
+            CurrentScreen::Boards => (
+                KeyContext::Boards,
+                &[
+                    (&["switch_board"], "Open"),
+                    (&["boards_cursor_down", "boards_cursor_up"], "Move"),
+                    (&["close_boards"], "Quit Boards"),
+                ],
+            ),
         };
+        let key_hints = Span::styled(
+            self.key_hints(context, hints),
+            Style::default().fg(Color::White),
+        );
         let key_hints_footer =
             Paragraph::new(Line::from(key_hints)).block(Block::default().borders(Borders::ALL));
 
@@ -132,28 +312,27 @@ impl App {
         frame.render_widget(mode_footer, footer_chunks[0]);
         frame.render_widget(key_hints_footer, footer_chunks[1]);
 
-        if self.current_screen == CurrentScreen::Editing {
+        if let (CurrentScreen::Editing, Some(state)) =
+            (&self.current_screen, &self.currently_editing_task)
+        {
+            let cur_task = &state.draft;
             let area = centered_rect(60, 50, frame.size());
             let gray_background = Style::default().bg(Color::Black);
-            frame.render_widget(Block::default().style(gray_background), area);
-
-            let new_state = TaskEditState {
-                currently_editing: Some(TaskField::Title),
-                cur_value: String::new(),
-                is_new_task: true,
-                has_changed: true,
-                mode: TaskEditMode::Normal,
-            };
-            let state = self.currently_editing_task.as_ref().unwrap_or(&new_state);
-            self.currently_editing_task = Some(state.clone());
+            frame.render_widget(Clear, area);
+            frame.render_widget(
+                Block::default()
+                    .title(if state.is_new_task {
+                        "New Task"
+                    } else {
+                        "Edit Task"
+                    })
+                    .borders(Borders::ALL)
+                    .style(gray_background),
+                area,
+            );
 
-            let currently_editing_field: TaskField = self
-                .currently_editing_task
-                .as_ref()
-                .unwrap()
-                .currently_editing
-                .clone()
-                .unwrap();
+            let currently_editing_field =
+                state.currently_editing.clone().unwrap_or(TaskField::Title);
 
             let area = centered_rect(60, 50, frame.size());
             let chunks = Layout::default()
@@ -161,44 +340,285 @@ impl App {
                 .margin(1)
                 .constraints([
                     Constraint::Length(3),
-                    Constraint::Percentage(80),
-                    Constraint::Length(3),
+                    Constraint::Min(3),
+                    Constraint::Length(4),
                 ])
                 .split(area);
 
-            let title_block = Block::default().title("Title").borders(Borders::ALL).style(
-                if currently_editing_field == TaskField::Title {
+            // Fields with unsaved changes get a marker
+            let field_title = |name: &str, field: TaskField| {
+                if state.is_dirty(&field) {
+                    format!("{} *", name)
+                } else {
+                    name.to_string()
+                }
+            };
+
+            let title_block = Block::default()
+                .title(field_title("Title", TaskField::Title))
+                .borders(Borders::ALL)
+                .style(if currently_editing_field == TaskField::Title {
                     active_style
                 } else {
                     Style::default()
-                },
-            );
+                });
             let desc_block = Block::default()
-                .title("Description")
+                .title(field_title("Description", TaskField::Description))
                 .borders(Borders::ALL)
                 .style(if currently_editing_field == TaskField::Description {
                     active_style
                 } else {
                     Style::default()
                 });
-            let due_block = Block::default().title("Due").borders(Borders::ALL).style(
-                if currently_editing_field == TaskField::Due {
+            let due_block = Block::default()
+                .title(field_title("Due", TaskField::Due))
+                .borders(Borders::ALL)
+                .style(if currently_editing_field == TaskField::Due {
                     active_style
                 } else {
                     Style::default()
-                },
-            );
-
-            let cur_task = self.get_cur_task().unwrap();
+                });
 
-            let title_text = Paragraph::new(cur_task.title).block(title_block);
+            let title_text = Paragraph::new(cur_task.title.as_str()).block(title_block);
             frame.render_widget(title_text, chunks[0]);
 
-            let desc_text = Paragraph::new(cur_task.description).block(desc_block);
+            let desc_text = Paragraph::new(cur_task.description.as_str()).block(desc_block);
             frame.render_widget(desc_text, chunks[1]);
 
-            let due_text = Paragraph::new(cur_task.due.to_string()).block(due_block);
+            // While the due date is being edited, show the raw input along with what it means
+            let due_lines = if currently_editing_field == TaskField::Due {
+                let preview = match &state.due_error {
+                    Some(err) => Span::styled(err.clone(), Style::default().fg(Color::Red)),
+                    None => Span::styled(
+                        format!("→ {}", format_due(cur_task.due, DUE_PREVIEW_FORMAT)),
+                        Style::default().fg(Color::Gray),
+                    ),
+                };
+                vec![Line::from(state.cur_value.as_str()), Line::from(preview)]
+            } else {
+                vec![Line::from(format_due(cur_task.due, DUE_PREVIEW_FORMAT))]
+            };
+            let due_text = Paragraph::new(due_lines).block(due_block);
             frame.render_widget(due_text, chunks[2]);
         }
+
+        if self.current_screen == CurrentScreen::Palette {
+            self.render_palette(frame, active_style);
+        }
+
+        // The archive takes the board's place
+        if self.current_screen == CurrentScreen::Archive {
+            self.render_archive(frame, chunks[1], active_style);
+        }
+        if self.current_screen == CurrentScreen::Boards {
+            self.render_boards(frame, chunks[1], active_style);
+        }
+
+        if let Some(confirmation) = &self.confirmation {
+            self.render_confirmation(frame, confirmation);
+        }
+    }
+
+    fn render_archive(&self, frame: &mut Frame, area: Rect, active_style: Style) {
+        frame.render_widget(Clear, area);
+
+        let rows = self.archive.iter().enumerate().map(|(i, archived)| {
+            Row::new(vec![
+                archived.task.title.clone(),
+                archived.task.kanban_status.to_string(),
+                archived
+                    .archived_at
+                    .format(self.config.ui.date_format.as_str())
+                    .to_string(),
+            ])
+            .style(if i == self.archive_index {
+                active_style
+            } else {
+                Style::default()
+            })
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(50),
+                Constraint::Percentage(20),
+                Constraint::Percentage(30),
+            ],
+        )
+        .header(
+            Row::new(vec!["Title", "Column", "Archived"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(
+            Block::default()
+                .title(format!("Archive ({})", self.archive.len()))
+                .borders(Borders::ALL),
+        );
+
+        let mut table_state = TableState::default().with_selected(Some(self.archive_index));
+        frame.render_stateful_widget(table, area, &mut table_state);
+    }
+
+    fn render_boards(&self, frame: &mut Frame, area: Rect, active_style: Style) {
+        frame.render_widget(Clear, area);
+
+        // The open board is marked, since the highlight moves away from it
+        let rows = self.boards.iter().enumerate().map(|(i, board)| {
+            let marker = if board == self.board_name() { "*" } else { "" };
+            Row::new(vec![marker.to_string(), board.clone()]).style(if i == self.board_index {
+                active_style
+            } else {
+                Style::default()
+            })
+        });
+
+        let table = Table::new(rows, [Constraint::Length(2), Constraint::Min(1)]).block(
+            Block::default()
+                .title(format!("Boards ({})", self.boards.len()))
+                .borders(Borders::ALL),
+        );
+
+        let mut table_state = TableState::default().with_selected(Some(self.board_index));
+        frame.render_stateful_widget(table, area, &mut table_state);
+    }
+
+    /// Hints like `q: Quit | e: Edit Task`, with the first key bound to each action in
+    /// `context`. Actions without a key are left out.
+    fn key_hints(&self, context: KeyContext, hints: KeyHints) -> String {
+        hints
+            .iter()
+            .filter_map(|(actions, label)| {
+                let keys: Vec<String> = actions
+                    .iter()
+                    .filter_map(|action| self.keymap.first_key(context, action))
+                    .collect();
+                (!keys.is_empty()).then(|| format!("{}: {}", keys.join("/"), label))
+            })
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    fn render_confirmation(&self, frame: &mut Frame, confirmation: &Confirmation) {
+        let (title, prompt) = match confirmation {
+            Confirmation::ArchiveTask(id) => {
+                let title = self
+                    .locate(*id)
+                    .map_or("", |(status, index)| &self.task_list[&status][index].title);
+                (
+                    "Delete Task",
+                    format!("Move \"{}\" to the archive? (y/n)", title),
+                )
+            }
+            Confirmation::MoveOverLimit { status, .. } => {
+                let limit = self.columns.limit(status).unwrap_or_default();
+                (
+                    "Column Full",
+                    format!(
+                        "{} is at its limit of {} tasks. Move the task there anyway? (y/n)",
+                        status, limit
+                    ),
+                )
+            }
+            Confirmation::AddOverLimit { status } => {
+                let limit = self.columns.limit(status).unwrap_or_default();
+                (
+                    "Column Full",
+                    format!(
+                        "{} is at its limit of {} tasks. Add the task there anyway? (y/n)",
+                        status, limit
+                    ),
+                )
+            }
+            Confirmation::RestoreOverLimit { status, .. } => {
+                let limit = self.columns.limit(status).unwrap_or_default();
+                (
+                    "Column Full",
+                    format!(
+                        "{} is at its limit of {} tasks. Restore the task there anyway? (y/n)",
+                        status, limit
+                    ),
+                )
+            }
+        };
+
+        let area = centered_rect(40, 20, frame.size());
+        frame.render_widget(Clear, area);
+        let popup = Paragraph::new(prompt)
+            .wrap(Wrap { trim: true })
+            .block(Block::default().title(title).borders(Borders::ALL));
+        frame.render_widget(popup, area);
+    }
+
+    fn render_palette(&self, frame: &mut Frame, active_style: Style) {
+        let area = centered_rect(50, 40, frame.size());
+        frame.render_widget(Clear, area);
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)])
+            .split(area);
+
+        let query = self
+            .palette
+            .as_ref()
+            .map_or("", |palette| palette.query.as_str());
+        let input = Paragraph::new(format!(":{}", query))
+            .block(Block::default().title("Command").borders(Borders::ALL));
+        frame.render_widget(input, chunks[0]);
+
+        let selected = self.palette.as_ref().map_or(0, |palette| palette.selected);
+        let match_style = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+
+        let rows =
+            self.palette_matches()
+                .into_iter()
+                .enumerate()
+                .map(|(i, (action, fuzzy_match))| {
+                    let name: Vec<Span> = action
+                        .name()
+                        .chars()
+                        .enumerate()
+                        .map(|(j, c)| {
+                            if fuzzy_match.indices.contains(&j) {
+                                Span::styled(c.to_string(), match_style)
+                            } else {
+                                Span::raw(c.to_string())
+                            }
+                        })
+                        .collect();
+                    let keys = self
+                        .keymap
+                        .keys_for(action.context(), action.name())
+                        .iter()
+                        .map(|keys| keys.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" ");
+
+                    Row::new(vec![
+                        Cell::from(Line::from(name)),
+                        Cell::from(action.description().to_string()),
+                        Cell::from(keys),
+                    ])
+                    .style(if i == selected {
+                        active_style
+                    } else {
+                        Style::default()
+                    })
+                });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(35),
+                Constraint::Percentage(50),
+                Constraint::Percentage(15),
+            ],
+        )
+        .block(Block::default().borders(Borders::ALL));
+
+        // Let the table scroll to keep the selection in view
+        let mut table_state = TableState::default().with_selected(Some(selected));
+        frame.render_stateful_widget(table, chunks[1], &mut table_state);
     }
 }